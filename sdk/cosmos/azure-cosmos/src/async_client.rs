@@ -0,0 +1,1009 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use azure_data_cosmos::CosmosClient as RustCosmosClient;
+use azure_data_cosmos::{QueryOptions, ChangeFeedOptions, ChangeFeedPage, BatchResponse};
+use azure_data_cosmos::models::{ContainerProperties, PartitionKeyDefinition};
+use azure_data_cosmos::ItemOptions;
+use azure_core::credentials::TokenCredential;
+use std::collections::HashMap;
+use std::sync::Arc;
+use serde_json::Value;
+use crate::client::PyTokenCredential;
+use crate::diagnostics::CosmosResponseDiagnostics;
+use crate::exceptions::map_error;
+use crate::retry::{self, RetryPolicy};
+use crate::utils::{extract_partition_key_from_body, py_object_to_json, python_to_partition_key};
+
+/// Async counterparts of `CosmosClient`/`DatabaseClient`/`ContainerClient`. Every method
+/// bridges its Tokio future to the calling coroutine with `pyo3_asyncio::tokio::future_into_py`
+/// instead of `TOKIO_RUNTIME.block_on`, so `await client.create_database(...)` suspends the
+/// event loop rather than blocking the thread running it. `future_into_py` lazily spins up a
+/// single shared multi-thread runtime the first time it's used and reuses it for every
+/// subsequent call, so this never spawns a new `Runtime` per client.
+#[pyclass(subclass)]
+pub struct AsyncCosmosClient {
+    inner: Arc<RustCosmosClient>,
+    retry_policy: Arc<RetryPolicy>,
+}
+
+#[pymethods]
+impl AsyncCosmosClient {
+    #[new]
+    #[pyo3(signature = (url, credential=None, **kwargs))]
+    pub fn new(
+        url: String,
+        credential: Option<PyObject>,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<Self> {
+        let retry_policy = Arc::new(retry::policy_from_kwargs(kwargs)?);
+
+        Python::with_gil(|py| {
+            let client = if let Some(cred) = credential {
+                if let Ok(key) = cred.extract::<String>(py) {
+                    RustCosmosClient::with_key(&url, key.into(), None)
+                        .map_err(map_error)?
+                } else if cred.as_ref(py).hasattr("get_token")? {
+                    let token_credential: Arc<dyn TokenCredential> = Arc::new(PyTokenCredential::new(cred));
+                    RustCosmosClient::with_token_credential(&url, token_credential, None)
+                        .map_err(map_error)?
+                } else {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "credential must be an account key string or a token credential exposing get_token(scopes)"
+                    ));
+                }
+            } else {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "credential parameter is required"
+                ));
+            };
+
+            Ok(Self {
+                inner: Arc::new(client),
+                retry_policy,
+            })
+        })
+    }
+
+    /// Create a new database. Returns an awaitable resolving to (AsyncDatabaseClient, diagnostics).
+    #[pyo3(signature = (id, **_kwargs))]
+    pub fn create_database<'py>(
+        &self,
+        py: Python<'py>,
+        id: String,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let inner = self.inner.clone();
+        let retry_policy = self.retry_policy.clone();
+        let id_clone = id.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let inner = inner.clone();
+                let id_clone = id_clone.clone();
+                async move { inner.create_database(&id_clone, None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let client = AsyncDatabaseClient::new(inner, id, retry_policy);
+                Ok((client.into_py(py), diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Get a database client (no I/O, so this stays synchronous like the blocking client).
+    pub fn get_database_client(&self, database_id: String) -> PyResult<AsyncDatabaseClient> {
+        Ok(AsyncDatabaseClient::new(self.inner.clone(), database_id, self.retry_policy.clone()))
+    }
+
+    /// Delete a database. Returns an awaitable resolving to diagnostics.
+    #[pyo3(signature = (database_id, **_kwargs))]
+    pub fn delete_database<'py>(
+        &self,
+        py: Python<'py>,
+        database_id: String,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let inner = self.inner.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = inner.database_client(&database_id);
+                async move { db_client.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| Ok(diagnostics.into_py(py)))
+        })
+    }
+
+    /// List all databases. Returns an awaitable resolving to a list of dicts.
+    ///
+    /// Unlike the sync client's `list_databases`, this drains the whole stream before
+    /// resolving rather than returning a page-at-a-time iterator; `QueryIterator` has no
+    /// async (`__anext__`) form. `max_item_count`/`continuation_token` still cap/resume the
+    /// underlying pages; per-page continuation tokens aren't surfaced here.
+    #[pyo3(signature = (**kwargs))]
+    pub fn list_databases<'py>(&self, py: Python<'py>, kwargs: Option<&PyDict>) -> PyResult<&'py PyAny> {
+        let inner = self.inner.clone();
+        let retry_policy = self.retry_policy.clone();
+        let max_item_count = crate::utils::extract_max_item_count(kwargs)?;
+        let continuation_token = crate::utils::extract_continuation_token(kwargs)?;
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            use futures::StreamExt;
+
+            let options = QueryOptions {
+                max_item_count,
+                continuation_token,
+                ..Default::default()
+            };
+            let mut stream = inner.query_databases::<Value>("SELECT * FROM databases", Some(options))
+                .map_err(map_error)?;
+
+            let mut items = Vec::new();
+            loop {
+                let (page, _stats) = retry_policy.execute(|| {
+                    let stream = &mut stream;
+                    async move { stream.next().await.transpose() }
+                }).await;
+                match page.map_err(map_error)? {
+                    Some(page) => items.extend(page.items),
+                    None => break,
+                }
+            }
+
+            Python::with_gil(|py| {
+                let mut py_items = Vec::with_capacity(items.len());
+                for item in &items {
+                    py_items.push(crate::utils::json_value_to_pyobject(py, item)?);
+                }
+                Ok(py_items.into_py(py))
+            })
+        })
+    }
+}
+
+#[pyclass(subclass)]
+pub struct AsyncDatabaseClient {
+    cosmos_client: Arc<RustCosmosClient>,
+    database_id: String,
+    retry_policy: Arc<RetryPolicy>,
+}
+
+impl AsyncDatabaseClient {
+    pub fn new(cosmos_client: Arc<RustCosmosClient>, database_id: String, retry_policy: Arc<RetryPolicy>) -> Self {
+        Self { cosmos_client, database_id, retry_policy }
+    }
+}
+
+#[pymethods]
+impl AsyncDatabaseClient {
+    /// Create a new container. Returns an awaitable resolving to (AsyncContainerClient, diagnostics).
+    #[pyo3(signature = (id, partition_key, **_kwargs))]
+    pub fn create_container<'py>(
+        &self,
+        py: Python<'py>,
+        id: String,
+        partition_key: &PyDict,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        let paths = partition_key.get_item("paths")?
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("partition_key must have 'paths'"))?;
+        let path_list = paths.extract::<Vec<String>>()?;
+        if path_list.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("partition_key paths cannot be empty"));
+        }
+
+        let container_id = id.clone();
+        let partition_key_definition = if path_list.len() > 1 {
+            PartitionKeyDefinition {
+                paths: path_list.iter().cloned().map(Into::into).collect(),
+                kind: "MultiHash".into(),
+                ..Default::default()
+            }
+        } else {
+            PartitionKeyDefinition::from(path_list[0].clone())
+        };
+        let partition_key_paths = path_list.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let db_client = cosmos_client.database_client(&database_id);
+            let props = ContainerProperties {
+                id: container_id.into(),
+                partition_key: partition_key_definition,
+                ..Default::default()
+            };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = &db_client;
+                let props = props.clone();
+                async move { db_client.create_container(props, None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let client = AsyncContainerClient::with_partition_key_paths(
+                    cosmos_client.clone(),
+                    database_id.clone(),
+                    id.clone(),
+                    partition_key_paths.clone(),
+                    retry_policy.clone(),
+                );
+                Ok((client.into_py(py), diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Get a container client (no I/O, so this stays synchronous like the blocking client).
+    pub fn get_container_client(&self, container_id: String) -> PyResult<AsyncContainerClient> {
+        Ok(AsyncContainerClient::new(
+            self.cosmos_client.clone(),
+            self.database_id.clone(),
+            container_id,
+            self.retry_policy.clone(),
+        ))
+    }
+
+    /// Delete a container. Returns an awaitable resolving to diagnostics.
+    #[pyo3(signature = (container_id, **_kwargs))]
+    pub fn delete_container<'py>(
+        &self,
+        py: Python<'py>,
+        container_id: String,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let db_client = cosmos_client.database_client(&database_id);
+            let container = db_client.container_client(&container_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                async move { container.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| Ok(diagnostics.into_py(py)))
+        })
+    }
+
+    /// Read database properties. Returns an awaitable resolving to (properties_dict, diagnostics).
+    #[pyo3(signature = (**_kwargs))]
+    pub fn read<'py>(&self, py: Python<'py>, _kwargs: Option<&PyDict>) -> PyResult<&'py PyAny> {
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let db_client = cosmos_client.database_client(&database_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = &db_client;
+                async move { db_client.read(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let dict = crate::utils::json_value_to_pyobject(py, &body_value)?;
+                Ok((dict, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Delete this database. Returns an awaitable resolving to diagnostics.
+    #[pyo3(signature = (**_kwargs))]
+    pub fn delete<'py>(&self, py: Python<'py>, _kwargs: Option<&PyDict>) -> PyResult<&'py PyAny> {
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let db_client = cosmos_client.database_client(&database_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = &db_client;
+                async move { db_client.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| Ok(diagnostics.into_py(py)))
+        })
+    }
+
+    #[getter]
+    pub fn id(&self) -> PyResult<String> {
+        Ok(self.database_id.clone())
+    }
+}
+
+#[pyclass(subclass)]
+pub struct AsyncContainerClient {
+    cosmos_client: Arc<RustCosmosClient>,
+    database_id: String,
+    container_id: String,
+    partition_key_paths: Vec<String>,
+    retry_policy: Arc<RetryPolicy>,
+}
+
+impl AsyncContainerClient {
+    pub fn new(cosmos_client: Arc<RustCosmosClient>, database_id: String, container_id: String, retry_policy: Arc<RetryPolicy>) -> Self {
+        Self { cosmos_client, database_id, container_id, partition_key_paths: Vec::new(), retry_policy }
+    }
+
+    pub fn with_partition_key_paths(
+        cosmos_client: Arc<RustCosmosClient>,
+        database_id: String,
+        container_id: String,
+        partition_key_paths: Vec<String>,
+        retry_policy: Arc<RetryPolicy>,
+    ) -> Self {
+        Self { cosmos_client, database_id, container_id, partition_key_paths, retry_policy }
+    }
+}
+
+#[pymethods]
+impl AsyncContainerClient {
+    /// Create a new item. Returns an awaitable resolving to (item_dict, diagnostics).
+    #[pyo3(signature = (body, **kwargs))]
+    pub fn create_item<'py>(
+        &self,
+        py: Python<'py>,
+        body: &'py PyAny,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let item_value = py_object_to_json(py, body)?;
+        let partition_key = if let Ok(dict) = body.downcast::<PyDict>() {
+            extract_partition_key_from_body(py, dict, &self.partition_key_paths, kwargs)?
+        } else if let Some(kw) = kwargs {
+            match kw.get_item("partition_key")? {
+                Some(pk) => python_to_partition_key(py, pk)?,
+                None => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Partition key must be provided in kwargs when body is a JSON string"
+                )),
+            }
+        } else {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Partition key must be provided in kwargs when body is a JSON string"
+            ));
+        };
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+            let options = ItemOptions { enable_content_response_on_write: true, ..Default::default() };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let partition_key = partition_key.clone();
+                let item_value = item_value.clone();
+                let options = options.clone();
+                async move { container.create_item(partition_key, item_value, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let dict = crate::utils::json_value_to_pyobject(py, &body_value)?;
+                Ok((dict, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Read an item by ID and partition key. Returns an awaitable resolving to (item_dict, diagnostics).
+    #[pyo3(signature = (item, partition_key, **_kwargs))]
+    pub fn read_item<'py>(
+        &self,
+        py: Python<'py>,
+        item: String,
+        partition_key: PyObject,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let pk = python_to_partition_key(py, partition_key.as_ref(py))?;
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let item = &item;
+                async move { container.read_item::<Value>(pk, item, None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let dict = crate::utils::json_value_to_pyobject(py, &body_value)?;
+                Ok((dict, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Upsert an item (create or replace). Returns an awaitable resolving to (item_dict, diagnostics).
+    #[pyo3(signature = (body, **kwargs))]
+    pub fn upsert_item<'py>(
+        &self,
+        py: Python<'py>,
+        body: &'py PyAny,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let item_value = py_object_to_json(py, body)?;
+        let partition_key = if let Ok(dict) = body.downcast::<PyDict>() {
+            extract_partition_key_from_body(py, dict, &self.partition_key_paths, kwargs)?
+        } else {
+            crate::utils::extract_partition_key_from_kwargs(py, kwargs)?
+        };
+        let if_match = crate::utils::extract_etag(kwargs)?;
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+            let options = ItemOptions { enable_content_response_on_write: true, if_match, ..Default::default() };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let partition_key = partition_key.clone();
+                let item_value = item_value.clone();
+                let options = options.clone();
+                async move { container.upsert_item(partition_key, item_value, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let dict = crate::utils::json_value_to_pyobject(py, &body_value)?;
+                Ok((dict, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Replace an item. Returns an awaitable resolving to (item_dict, diagnostics).
+    #[pyo3(signature = (item, body, **kwargs))]
+    pub fn replace_item<'py>(
+        &self,
+        py: Python<'py>,
+        item: String,
+        body: &'py PyAny,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let item_value = py_object_to_json(py, body)?;
+        let partition_key = if let Ok(dict) = body.downcast::<PyDict>() {
+            extract_partition_key_from_body(py, dict, &self.partition_key_paths, kwargs)?
+        } else {
+            crate::utils::extract_partition_key_from_kwargs(py, kwargs)?
+        };
+        let if_match = crate::utils::extract_etag(kwargs)?;
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+            let options = ItemOptions { enable_content_response_on_write: true, if_match, ..Default::default() };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let partition_key = partition_key.clone();
+                let item = &item;
+                let item_value = item_value.clone();
+                let options = options.clone();
+                async move { container.replace_item(partition_key, item, item_value, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let dict = crate::utils::json_value_to_pyobject(py, &body_value)?;
+                Ok((dict, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Delete an item. Returns an awaitable resolving to diagnostics.
+    #[pyo3(signature = (item, partition_key, **_kwargs))]
+    pub fn delete_item<'py>(
+        &self,
+        py: Python<'py>,
+        item: String,
+        partition_key: PyObject,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let pk = python_to_partition_key(py, partition_key.as_ref(py))?;
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let item = &item;
+                async move { container.delete_item(pk, item, None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| Ok(diagnostics.into_py(py)))
+        })
+    }
+
+    /// Patch an item with the JSON Patch operation set (add/set/replace/remove/incr/move).
+    /// Returns an awaitable resolving to (item_dict, diagnostics).
+    #[pyo3(signature = (item, partition_key, patch_operations, **kwargs))]
+    pub fn patch_item<'py>(
+        &self,
+        py: Python<'py>,
+        item: String,
+        partition_key: PyObject,
+        patch_operations: &PyList,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let pk = python_to_partition_key(py, partition_key.as_ref(py))?;
+        let patch_doc = crate::utils::parse_patch_operations(py, patch_operations)?;
+        let if_match = crate::utils::extract_etag(kwargs)?;
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+            let options = ItemOptions { enable_content_response_on_write: true, if_match, ..Default::default() };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let item = &item;
+                let patch_doc = patch_doc.clone();
+                let options = options.clone();
+                async move { container.patch_item(pk, item, patch_doc, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let dict = crate::utils::json_value_to_pyobject(py, &body_value)?;
+                Ok((dict, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Execute a transactional batch of operations scoped to a single partition key. See the
+    /// sync `ContainerClient::execute_item_batch` doc comment for the accepted operation
+    /// shapes and failure semantics; this is the same behavior bridged to a coroutine.
+    /// Returns an awaitable resolving to (list_of_(status_code, resource_or_None), diagnostics).
+    #[pyo3(signature = (partition_key, operations, **_kwargs))]
+    pub fn execute_item_batch<'py>(
+        &self,
+        py: Python<'py>,
+        partition_key: PyObject,
+        operations: &PyList,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let pk = python_to_partition_key(py, partition_key.as_ref(py))?;
+        let parsed_operations = crate::utils::parse_batch_operations(py, operations)?;
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+            let options = ItemOptions { enable_content_response_on_write: true, ..Default::default() };
+
+            // Same rationale as the sync client: a transactional batch isn't idempotent to
+            // blindly retry as a whole, so retries here only cover the request failing
+            // outright (429/5xx/connection) before Cosmos executed it.
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let parsed_operations = parsed_operations.clone();
+                let options = options.clone();
+                async move { container.execute_batch(pk, parsed_operations, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            let batch_response: BatchResponse = response.into_body().json()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize batch response: {}", e)))?;
+
+            if let Some((index, failed)) = batch_response.results.iter().enumerate()
+                .find(|(_, r)| r.status_code >= 300 && r.status_code != 424)
+            {
+                return Err(map_error(typespec::error::Error::message(
+                    typespec::error::ErrorKind::HttpResponse {
+                        status: failed.status_code,
+                        error_code: failed.sub_status_code.map(|s| s.to_string()),
+                    },
+                    format!("Batch operation at index {} failed with status {}", index, failed.status_code),
+                )));
+            }
+
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let mut py_results = Vec::new();
+                for result in batch_response.results {
+                    let resource: PyObject = match result.resource_body {
+                        Some(ref body) => crate::utils::json_value_to_pyobject(py, body)?,
+                        None => py.None(),
+                    };
+                    py_results.push(PyTuple::new(py, &[result.status_code.to_object(py), resource]));
+                }
+                Ok((py_results, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Query items with SQL. See the sync `ContainerClient::query_items` doc comment for
+    /// the `partition_key`/`max_item_count`/`continuation_token` kwargs and cross-partition
+    /// fan-out behavior. Returns an awaitable resolving to
+    /// (list_of_dicts, continuation_token, diagnostics). The diagnostics carry the retry count
+    /// and the request charge of failed attempts only; the pages' own response headers and
+    /// request charges are not included.
+    #[pyo3(signature = (query, **kwargs))]
+    pub fn query_items<'py>(
+        &self,
+        py: Python<'py>,
+        query: String,
+        kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let partition_key_opt = if let Some(kw) = kwargs {
+            if let Ok(Some(pk)) = kw.get_item("partition_key") {
+                Some(python_to_partition_key(py, pk)?)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+        let max_item_count = crate::utils::extract_max_item_count(kwargs)?;
+        let incoming_continuation = crate::utils::extract_continuation_token(kwargs)?;
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            use futures::StreamExt;
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+            let mut stats = retry::RetryStats::default();
+
+            let (items, continuation_state) = if let Some(pk) = partition_key_opt {
+                let options = QueryOptions {
+                    max_item_count,
+                    continuation_token: incoming_continuation,
+                    ..Default::default()
+                };
+                let mut stream = container.query_items::<Value>(&query, pk, Some(options)).map_err(map_error)?;
+
+                let mut result = Vec::new();
+                let mut continuation = None;
+                let (page, page_stats) = retry_policy.execute(|| {
+                    let stream = &mut stream;
+                    async move { stream.next().await.transpose() }
+                }).await;
+                stats.retry_count += page_stats.retry_count;
+                stats.request_charge += page_stats.request_charge;
+                if let Some(page) = page.map_err(map_error)? {
+                    continuation = page.continuation_token.clone();
+                    result.extend(page.items);
+                }
+
+                (result, continuation)
+            } else {
+                let ranges = container.read_partition_key_ranges(None)
+                    .await
+                    .map_err(map_error)?;
+
+                let mut per_range_continuation: HashMap<String, String> = incoming_continuation
+                    .as_deref()
+                    .and_then(|token| serde_json::from_str(token).ok())
+                    .unwrap_or_default();
+                let resuming = !per_range_continuation.is_empty();
+
+                let mut result = Vec::new();
+                for range in ranges {
+                    let range_continuation = per_range_continuation.remove(&range.id);
+                    if resuming && range_continuation.is_none() {
+                        // Drained on an earlier page, so it has nothing left to resume.
+                        continue;
+                    }
+                    let options = QueryOptions {
+                        max_item_count,
+                        continuation_token: range_continuation,
+                        ..Default::default()
+                    };
+
+                    let mut stream = container
+                        .query_items_in_range::<Value>(&query, &range, Some(options))
+                        .map_err(map_error)?;
+
+                    let (page, page_stats) = retry_policy.execute(|| {
+                        let stream = &mut stream;
+                        async move { stream.next().await.transpose() }
+                    }).await;
+                    stats.retry_count += page_stats.retry_count;
+                    stats.request_charge += page_stats.request_charge;
+
+                    if let Some(page) = page.map_err(map_error)? {
+                        if let Some(token) = page.continuation_token {
+                            per_range_continuation.insert(range.id.clone(), token);
+                        }
+                        result.extend(page.items);
+                    }
+                }
+
+                let continuation = if per_range_continuation.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::to_string(&per_range_continuation)
+                        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to encode continuation token: {}", e)))?)
+                };
+
+                (result, continuation)
+            };
+
+            let mut header_map = HashMap::new();
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                let mut py_items = Vec::with_capacity(items.len());
+                for item in &items {
+                    py_items.push(crate::utils::json_value_to_pyobject(py, item)?);
+                }
+                Ok((py_items, continuation_state, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Read the change feed for this container. See the sync
+    /// `ContainerClient::query_items_change_feed` doc comment for the continuation/start-time
+    /// semantics. Returns an awaitable resolving to (list_of_changed_items, diagnostics).
+    #[pyo3(signature = (partition_key=None, continuation=None, start_time=None, **_kwargs))]
+    pub fn query_items_change_feed<'py>(
+        &self,
+        py: Python<'py>,
+        partition_key: Option<PyObject>,
+        continuation: Option<String>,
+        start_time: Option<String>,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<&'py PyAny> {
+        let pk_opt = partition_key
+            .map(|pk| python_to_partition_key(py, pk.as_ref(py)))
+            .transpose()?;
+
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        let options = ChangeFeedOptions {
+            continuation,
+            start_time,
+            ..Default::default()
+        };
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk_opt = pk_opt.clone();
+                let options = options.clone();
+                async move { container.query_change_feed::<Value>(pk_opt, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            // A 304 Not Modified response has no body at all, so only parse one out when
+            // there's actually new content to read.
+            let has_changes = response.status() != azure_core::http::StatusCode::NotModified;
+            let items = if has_changes {
+                let feed: ChangeFeedPage<Value> = response.into_body().json()
+                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize change feed response: {}", e)))?;
+                feed.items
+            } else {
+                Vec::new()
+            };
+
+            header_map.insert("has_more_changes".to_string(), has_changes.to_string());
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| {
+                if !has_changes {
+                    return Ok((Vec::<PyObject>::new(), diagnostics.into_py(py)));
+                }
+                let mut py_items = Vec::new();
+                for item in &items {
+                    py_items.push(crate::utils::json_value_to_pyobject(py, item)?);
+                }
+                Ok((py_items, diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Read container properties. Returns an awaitable resolving to (properties_dict, diagnostics).
+    #[pyo3(signature = (**_kwargs))]
+    pub fn read<'py>(&self, py: Python<'py>, _kwargs: Option<&PyDict>) -> PyResult<&'py PyAny> {
+        let container_id = self.container_id.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            Python::with_gil(|py| {
+                let dict = PyDict::new(py);
+                dict.set_item("id", &container_id)?;
+                let diagnostics = CosmosResponseDiagnostics::from_headers(HashMap::new(), 0.0);
+                Ok((dict.into_py(py), diagnostics.into_py(py)))
+            })
+        })
+    }
+
+    /// Delete this container. Returns an awaitable resolving to diagnostics.
+    #[pyo3(signature = (**_kwargs))]
+    pub fn delete<'py>(&self, py: Python<'py>, _kwargs: Option<&PyDict>) -> PyResult<&'py PyAny> {
+        let cosmos_client = self.cosmos_client.clone();
+        let database_id = self.database_id.clone();
+        let container_id = self.container_id.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let container = cosmos_client.database_client(&database_id).container_client(&container_id);
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                async move { container.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut header_map: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+            header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+            let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+            Python::with_gil(|py| Ok(diagnostics.into_py(py)))
+        })
+    }
+
+    #[getter]
+    pub fn id(&self) -> PyResult<String> {
+        Ok(self.container_id.clone())
+    }
+}
@@ -1,10 +1,17 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use azure_data_cosmos::CosmosClient as RustCosmosClient;
+use azure_data_cosmos::QueryOptions;
+use azure_core::credentials::{AccessToken, Secret, TokenCredential};
+use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
+use time::OffsetDateTime;
 use crate::database::DatabaseClient;
+use crate::diagnostics::CosmosResponseDiagnostics;
 use crate::exceptions::map_error;
-use crate::utils::empty_headers_dict;
+use crate::iterator::{Page, QueryIterator};
+use crate::retry::{self, RetryPolicy};
 use once_cell::sync::Lazy;
 use tokio::runtime::Runtime;
 
@@ -15,31 +22,83 @@ static TOKIO_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
         .expect("Failed to create Tokio runtime")
 });
 
+/// Adapts a Python object exposing `get_token(scopes) -> AccessToken` (the shape produced
+/// by `azure-identity`'s credentials, e.g. `DefaultAzureCredential`) into the Rust SDK's
+/// `TokenCredential` trait, so users can authenticate with managed identity / service
+/// principals instead of an account key.
+pub(crate) struct PyTokenCredential {
+    credential: PyObject,
+}
+
+impl PyTokenCredential {
+    pub(crate) fn new(credential: PyObject) -> Self {
+        Self { credential }
+    }
+}
+
+#[async_trait]
+impl TokenCredential for PyTokenCredential {
+    async fn get_token(&self, scopes: &[&str]) -> azure_core::Result<AccessToken> {
+        let credential = Python::with_gil(|py| self.credential.clone_ref(py));
+        let scopes: Vec<String> = scopes.iter().map(|s| s.to_string()).collect();
+
+        tokio::task::spawn_blocking(move || {
+            Python::with_gil(|py| {
+                let result = credential.call_method1(py, "get_token", (scopes,))
+                    .map_err(|e| azure_core::Error::new(azure_core::error::ErrorKind::Credential, e))?;
+                let token: String = result.getattr(py, "token")
+                    .and_then(|v| v.extract(py))
+                    .map_err(|e| azure_core::Error::new(azure_core::error::ErrorKind::Credential, e))?;
+                let expires_on: i64 = result.getattr(py, "expires_on")
+                    .and_then(|v| v.extract(py))
+                    .map_err(|e| azure_core::Error::new(azure_core::error::ErrorKind::Credential, e))?;
+
+                let expires_on = OffsetDateTime::from_unix_timestamp(expires_on)
+                    .map_err(|e| azure_core::Error::new(azure_core::error::ErrorKind::Credential, e))?;
+
+                Ok(AccessToken::new(Secret::new(token), expires_on))
+            })
+        })
+        .await
+        .map_err(|e| azure_core::Error::new(azure_core::error::ErrorKind::Credential, e))?
+    }
+}
+
 #[pyclass(subclass)]
 pub struct CosmosClient {
     inner: Arc<RustCosmosClient>,
     #[allow(dead_code)]
     endpoint: String,
+    retry_policy: Arc<RetryPolicy>,
 }
 
 #[pymethods]
 impl CosmosClient {
     #[new]
-    #[pyo3(signature = (url, credential=None, **_kwargs))]
+    #[pyo3(signature = (url, credential=None, **kwargs))]
     pub fn new(
         url: String,
         credential: Option<PyObject>,
-        _kwargs: Option<&PyDict>,
+        kwargs: Option<&PyDict>,
     ) -> PyResult<Self> {
+        let retry_policy = Arc::new(retry::policy_from_kwargs(kwargs)?);
+
         Python::with_gil(|py| {
             let client = if let Some(cred) = credential {
                 // Check if credential is a string (key-based auth)
                 if let Ok(key) = cred.extract::<String>(py) {
                     RustCosmosClient::with_key(&url, key.into(), None)
                         .map_err(map_error)?
+                } else if cred.as_ref(py).hasattr("get_token")? {
+                    // Azure AD / managed identity / service principal credential, e.g.
+                    // one produced by azure-identity. Wrap it so the Rust SDK can call
+                    // back into Python for each token request.
+                    let token_credential: Arc<dyn TokenCredential> = Arc::new(PyTokenCredential::new(cred));
+                    RustCosmosClient::with_token_credential(&url, token_credential, None)
+                        .map_err(map_error)?
                 } else {
                     return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                        "Only key-based authentication is currently supported"
+                        "credential must be an account key string or a token credential exposing get_token(scopes)"
                     ));
                 }
             } else {
@@ -51,93 +110,135 @@ impl CosmosClient {
             Ok(Self {
                 inner: Arc::new(client),
                 endpoint: url,
+                retry_policy,
             })
         })
     }
 
     /// Create a new database
-    /// Returns tuple of (DatabaseClient, headers_dict)
+    /// Returns tuple of (DatabaseClient, diagnostics)
     #[pyo3(signature = (id, **_kwargs))]
-    pub fn create_database<'py>(
+    pub fn create_database(
         &self,
-        py: Python<'py>,
+        py: Python<'_>,
         id: String,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(DatabaseClient, &'py PyDict)> {
+    ) -> PyResult<(DatabaseClient, CosmosResponseDiagnostics)> {
         let client = self.inner.clone();
         let id_clone = id.clone();
-        
-        let _result = TOKIO_RUNTIME.block_on(async move {
-            client.create_database(&id_clone, None)
-                .await
-                .map_err(map_error)
-        })?;
-
-        // Return DatabaseClient and empty headers dict
-        // TODO: Extract actual headers from response when Rust SDK supports it
-        let headers = empty_headers_dict(py);
-        Ok((DatabaseClient::new(self.inner.clone(), id), headers))
+        let retry_policy = self.retry_policy.clone();
+
+        // Release the GIL for the blocking call: a token credential's get_token runs on a
+        // separate thread via spawn_blocking and needs to reacquire the GIL itself, which
+        // would deadlock forever against this thread if it held the GIL through block_on.
+        let (result, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            retry_policy.execute(|| {
+                let client = client.clone();
+                let id_clone = id_clone.clone();
+                async move { client.create_database(&id_clone, None).await }
+            }).await
+        }));
+        let response = result.map_err(map_error)?;
+
+        let mut header_map: HashMap<String, String> = HashMap::new();
+        for (name, value) in response.headers().iter() {
+            header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+        }
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+        Ok((DatabaseClient::new(self.inner.clone(), id, self.retry_policy.clone()), diagnostics))
     }
 
     /// Get a database client
     pub fn get_database_client(&self, database_id: String) -> PyResult<DatabaseClient> {
-        Ok(DatabaseClient::new(self.inner.clone(), database_id))
+        Ok(DatabaseClient::new(self.inner.clone(), database_id, self.retry_policy.clone()))
     }
 
     /// Delete a database
-    /// Returns headers_dict
+    /// Returns diagnostics
     #[pyo3(signature = (database_id, **_kwargs))]
-    pub fn delete_database<'py>(
+    pub fn delete_database(
         &self,
-        py: Python<'py>,
+        py: Python<'_>,
         database_id: String,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<&'py PyDict> {
-        let client = self.inner.database_client(&database_id);
-        
-        TOKIO_RUNTIME.block_on(async move {
-            client.delete(None)
-                .await
-                .map_err(map_error)
-        })?;
-
-        Ok(empty_headers_dict(py))
+    ) -> PyResult<CosmosResponseDiagnostics> {
+        let client = self.inner.clone();
+        let retry_policy = self.retry_policy.clone();
+
+        let (result, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            retry_policy.execute(|| {
+                let db_client = client.database_client(&database_id);
+                async move { db_client.delete(None).await }
+            }).await
+        }));
+        let response = result.map_err(map_error)?;
+
+        let mut header_map: HashMap<String, String> = HashMap::new();
+        for (name, value) in response.headers().iter() {
+            header_map.insert(name.as_str().to_string(), value.as_str().to_string());
+        }
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+
+        Ok(CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge))
     }
 
     /// List all databases
-    /// Returns tuple of (list_of_dicts, headers_dict)
-    #[pyo3(signature = (**_kwargs))]
-    pub fn list_databases<'py>(
-        &self,
-        py: Python<'py>,
-        _kwargs: Option<&PyDict>,
-    ) -> PyResult<(Vec<&'py PyDict>, &'py PyDict)> {
+    /// Returns a `QueryIterator` that pulls one page at a time through the shared Tokio
+    /// runtime rather than draining the whole account's database list up front. Pass
+    /// `max_item_count` to cap page size and `continuation_token` to resume a prior listing.
+    #[pyo3(signature = (**kwargs))]
+    pub fn list_databases(&self, kwargs: Option<&PyDict>) -> PyResult<QueryIterator> {
         let client = self.inner.clone();
-        
-        let databases = TOKIO_RUNTIME.block_on(async move {
-            let mut result = Vec::new();
-            let mut stream = client.query_databases("SELECT * FROM databases", None).map_err(map_error)?;
-            
+        let retry_policy = self.retry_policy.clone();
+        let max_item_count = crate::utils::extract_max_item_count(kwargs)?;
+        let continuation_token = crate::utils::extract_continuation_token(kwargs)?;
+
+        let options = QueryOptions {
+            max_item_count,
+            continuation_token,
+            ..Default::default()
+        };
+        let mut stream = client.query_databases::<serde_json::Value>("SELECT * FROM databases", Some(options))
+            .map_err(map_error)?;
+
+        let fetch_next: Box<dyn FnMut() -> PyResult<Option<Page>> + Send> = Box::new(move || {
             use futures::StreamExt;
-            while let Some(response) = stream.next().await {
-                match response {
-                    Ok(db) => result.push(db),
-                    Err(e) => return Err(map_error(e)),
+            TOKIO_RUNTIME.block_on(async {
+                let (result, stats) = retry_policy.execute(|| {
+                    let stream = &mut stream;
+                    async move {
+                        match stream.next().await {
+                            Some(page_result) => page_result.map(Some),
+                            None => Ok(None),
+                        }
+                    }
+                }).await;
+
+                match result {
+                    Ok(Some(page)) => {
+                        let mut headers: HashMap<String, String> = HashMap::new();
+                        if let Some(token) = &page.continuation_token {
+                            headers.insert("x-ms-continuation".to_string(), token.clone());
+                        }
+                        headers.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+                        // Only the charge of attempts that failed before this page; the
+                        // page's own request charge is not included.
+                        headers.insert("x-ms-request-charge".to_string(), stats.request_charge.to_string());
+                        Ok(Some(Page {
+                            items: page.items,
+                            continuation_token: page.continuation_token,
+                            headers,
+                        }))
+                    }
+                    Ok(None) => Ok(None),
+                    Err(e) => Err(map_error(e)),
                 }
-            }
-            
-            Ok::<_, PyErr>(result)
-        })?;
-
-        let mut py_databases = Vec::new();
-        for db in databases {
-            let dict = PyDict::new(py);
-            dict.set_item("id", format!("{:?}", db))?;
-            py_databases.push(dict);
-        }
+            })
+        });
 
-        let headers = empty_headers_dict(py);
-        Ok((py_databases, headers))
+        Ok(QueryIterator::new(fetch_next))
     }
 
     /// Context manager support
@@ -1,13 +1,17 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyTuple};
 use azure_data_cosmos::CosmosClient as RustCosmosClient;
 use azure_data_cosmos::PartitionKey as RustPartitionKey;
 use azure_data_cosmos::ItemOptions;
+use azure_data_cosmos::{BatchOperation, BatchResponse, QueryOptions, ChangeFeedOptions, ChangeFeedPage};
+use azure_data_cosmos::PatchDocument;
 use std::sync::Arc;
 use std::collections::HashMap;
 use serde_json::Value;
+use crate::diagnostics::CosmosResponseDiagnostics;
 use crate::exceptions::map_error;
-use crate::utils::{py_object_to_json, empty_headers_dict};
+use crate::retry::{self, RetryPolicy};
+use crate::utils::py_object_to_json;
 use once_cell::sync::Lazy;
 use tokio::runtime::Runtime;
 
@@ -24,25 +28,34 @@ pub struct ContainerClient {
     cosmos_client: Arc<RustCosmosClient>,
     database_id: String,
     container_id: String,
-    partition_key_path: Option<String>,  // e.g., "/pk" or "/category"
+    partition_key_paths: Vec<String>,  // e.g., ["/pk"] or ["/tenantId", "/userId"] for hierarchical keys
+    retry_policy: Arc<RetryPolicy>,
 }
 
 impl ContainerClient {
-    pub fn new(cosmos_client: Arc<RustCosmosClient>, database_id: String, container_id: String) -> Self {
+    pub fn new(cosmos_client: Arc<RustCosmosClient>, database_id: String, container_id: String, retry_policy: Arc<RetryPolicy>) -> Self {
         Self {
             cosmos_client,
             database_id,
             container_id,
-            partition_key_path: None,
+            partition_key_paths: Vec::new(),
+            retry_policy,
         }
     }
 
-    pub fn with_partition_key_path(cosmos_client: Arc<RustCosmosClient>, database_id: String, container_id: String, partition_key_path: String) -> Self {
+    pub fn with_partition_key_paths(
+        cosmos_client: Arc<RustCosmosClient>,
+        database_id: String,
+        container_id: String,
+        partition_key_paths: Vec<String>,
+        retry_policy: Arc<RetryPolicy>,
+    ) -> Self {
         Self {
             cosmos_client,
             database_id,
             container_id,
-            partition_key_path: Some(partition_key_path),
+            partition_key_paths,
+            retry_policy,
         }
     }
 }
@@ -51,40 +64,46 @@ impl ContainerClient {
 impl ContainerClient {
     /// Create a new item
     /// Accepts either a dict or a JSON string for the body
-    /// Returns tuple of (item_dict, headers_dict)
+    /// Returns tuple of (item_dict, diagnostics)
     #[pyo3(signature = (body, **_kwargs))]
     pub fn create_item<'py>(
         &self,
         py: Python<'py>,
         body: &'py PyAny,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
+        let retry_policy = self.retry_policy.clone();
+
         // Convert Python object (dict or string) to JSON using hybrid approach
         let item_value = py_object_to_json(py, body)?;
-        
+
         // Extract partition key from body or kwargs
         let partition_key = if let Ok(dict) = body.downcast::<PyDict>() {
             self.extract_partition_key(py, dict, _kwargs)?
         } else {
             // If body is a string, partition key must come from kwargs
-            self.extract_partition_key_from_kwargs(_kwargs)?
+            self.extract_partition_key_from_kwargs(py, _kwargs)?
         };
-        
+
         // Execute and get both headers and body
-        let (header_map, value) = TOKIO_RUNTIME.block_on(async move {
+        let (mut header_map, value, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
             // Enable content response on write to get the created item back
             let options = ItemOptions {
                 enable_content_response_on_write: true,
                 ..Default::default()
             };
 
-            let response = container.create_item(partition_key, item_value, Some(options))
-                .await
-                .map_err(map_error)?;
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let partition_key = partition_key.clone();
+                let item_value = item_value.clone();
+                let options = options.clone();
+                async move { container.create_item(partition_key, item_value, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
 
             // Extract headers into a HashMap before consuming the body
             let mut headers: HashMap<String, String> = HashMap::new();
@@ -96,25 +115,18 @@ impl ContainerClient {
             let body_value = response.into_body().json::<Value>()
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
 
-            Ok::<_, PyErr>((headers, body_value))
-        })?;
-
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
+            Ok::<_, PyErr>((headers, body_value, stats))
+        }))?;
 
-        let json_str = serde_json::to_string(&value)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON error: {}", e)))?;
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
 
-        let json_module = py.import("json")?;
-        let py_dict: &PyDict = json_module.call_method1("loads", (json_str,))?.extract()?;
-        Ok((py_dict, headers))
+        let py_dict: &PyDict = crate::utils::json_value_to_py_dict(py, &value)?;
+        Ok((py_dict, diagnostics))
     }
 
     /// Read an item by ID and partition key
-    /// Returns tuple of (item_dict, headers_dict)
+    /// Returns tuple of (item_dict, diagnostics)
     #[pyo3(signature = (item, partition_key, **_kwargs))]
     pub fn read_item<'py>(
         &self,
@@ -122,19 +134,24 @@ impl ContainerClient {
         item: String,
         partition_key: PyObject,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
-        let pk = self.python_to_partition_key(py, partition_key)?;
+        let retry_policy = self.retry_policy.clone();
+
+        let pk = crate::utils::python_to_partition_key(py, partition_key.as_ref(py))?;
         let item_id = item.clone();
-        
+
         // Execute and get both headers and body
-        let (header_map, value) = TOKIO_RUNTIME.block_on(async move {
-            let response = container.read_item::<Value>(pk, &item_id, None)
-                .await
-                .map_err(map_error)?;
+        let (mut header_map, value, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let item_id = &item_id;
+                async move { container.read_item::<Value>(pk, item_id, None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
 
             // Extract headers into a HashMap before consuming the body
             let mut headers: HashMap<String, String> = HashMap::new();
@@ -146,57 +163,58 @@ impl ContainerClient {
             let body_value = response.into_body().json::<Value>()
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
 
-            Ok::<_, PyErr>((headers, body_value))
-        })?;
+            Ok::<_, PyErr>((headers, body_value, stats))
+        }))?;
 
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
 
-        let json_str = serde_json::to_string(&value)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON error: {}", e)))?;
-        
-        let json_module = py.import("json")?;
-        let py_dict: &PyDict = json_module.call_method1("loads", (json_str,))?.extract()?;
-        Ok((py_dict, headers))
+        let py_dict: &PyDict = crate::utils::json_value_to_py_dict(py, &value)?;
+        Ok((py_dict, diagnostics))
     }
 
     /// Upsert an item (create or replace)
-    /// Returns tuple of (item_dict, headers_dict)
+    /// Returns tuple of (item_dict, diagnostics)
     #[pyo3(signature = (body, **_kwargs))]
     pub fn upsert_item<'py>(
         &self,
         py: Python<'py>,
         body: &'py PyAny,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
+        let retry_policy = self.retry_policy.clone();
+
         // Convert Python object (dict or string) to JSON using hybrid approach
         let item_value = py_object_to_json(py, body)?;
-        
+
         // Extract partition key from body or kwargs
         let partition_key = if let Ok(dict) = body.downcast::<PyDict>() {
             self.extract_partition_key(py, dict, _kwargs)?
         } else {
-            self.extract_partition_key_from_kwargs(_kwargs)?
+            self.extract_partition_key_from_kwargs(py, _kwargs)?
         };
-        
+        let if_match = self.extract_etag(_kwargs)?;
+
         // Execute and get both headers and body
-        let (header_map, value) = TOKIO_RUNTIME.block_on(async move {
+        let (mut header_map, value, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
             // Enable content response on write to get the upserted item back
             let options = ItemOptions {
                 enable_content_response_on_write: true,
+                if_match,
                 ..Default::default()
             };
 
-            let response = container.upsert_item(partition_key, item_value, Some(options))
-                .await
-                .map_err(map_error)?;
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let partition_key = partition_key.clone();
+                let item_value = item_value.clone();
+                let options = options.clone();
+                async move { container.upsert_item(partition_key, item_value, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
 
             // Extract headers into a HashMap before consuming the body
             let mut headers: HashMap<String, String> = HashMap::new();
@@ -208,25 +226,18 @@ impl ContainerClient {
             let body_value = response.into_body().json::<Value>()
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
 
-            Ok::<_, PyErr>((headers, body_value))
-        })?;
-
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
+            Ok::<_, PyErr>((headers, body_value, stats))
+        }))?;
 
-        let json_str = serde_json::to_string(&value)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON error: {}", e)))?;
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
 
-        let json_module = py.import("json")?;
-        let py_dict: &PyDict = json_module.call_method1("loads", (json_str,))?.extract()?;
-        Ok((py_dict, headers))
+        let py_dict: &PyDict = crate::utils::json_value_to_py_dict(py, &value)?;
+        Ok((py_dict, diagnostics))
     }
 
     /// Replace an item
-    /// Returns tuple of (item_dict, headers_dict)
+    /// Returns tuple of (item_dict, diagnostics)
     #[pyo3(signature = (item, body, **_kwargs))]
     pub fn replace_item<'py>(
         &self,
@@ -234,33 +245,42 @@ impl ContainerClient {
         item: String,
         body: &'py PyAny,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
+        let retry_policy = self.retry_policy.clone();
+
         // Convert Python object (dict or string) to JSON using hybrid approach
         let item_value = py_object_to_json(py, body)?;
-        
+
         // Extract partition key from body or kwargs
         let partition_key = if let Ok(dict) = body.downcast::<PyDict>() {
             self.extract_partition_key(py, dict, _kwargs)?
         } else {
-            self.extract_partition_key_from_kwargs(_kwargs)?
+            self.extract_partition_key_from_kwargs(py, _kwargs)?
         };
         let item_id = item.clone();
-        
+        let if_match = self.extract_etag(_kwargs)?;
+
         // Execute and get both headers and body
-        let (header_map, value) = TOKIO_RUNTIME.block_on(async move {
+        let (mut header_map, value, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
             // Enable content response on write to get the replaced item back
             let options = ItemOptions {
                 enable_content_response_on_write: true,
+                if_match,
                 ..Default::default()
             };
 
-            let response = container.replace_item(partition_key, &item_id, item_value, Some(options))
-                .await
-                .map_err(map_error)?;
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let partition_key = partition_key.clone();
+                let item_id = &item_id;
+                let item_value = item_value.clone();
+                let options = options.clone();
+                async move { container.replace_item(partition_key, item_id, item_value, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
 
             // Extract headers into a HashMap before consuming the body
             let mut headers: HashMap<String, String> = HashMap::new();
@@ -272,25 +292,18 @@ impl ContainerClient {
             let body_value = response.into_body().json::<Value>()
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
 
-            Ok::<_, PyErr>((headers, body_value))
-        })?;
-
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
+            Ok::<_, PyErr>((headers, body_value, stats))
+        }))?;
 
-        let json_str = serde_json::to_string(&value)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON error: {}", e)))?;
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
 
-        let json_module = py.import("json")?;
-        let py_dict: &PyDict = json_module.call_method1("loads", (json_str,))?.extract()?;
-        Ok((py_dict, headers))
+        let py_dict: &PyDict = crate::utils::json_value_to_py_dict(py, &value)?;
+        Ok((py_dict, diagnostics))
     }
 
     /// Delete an item
-    /// Returns headers_dict
+    /// Returns diagnostics
     #[pyo3(signature = (item, partition_key, **_kwargs))]
     pub fn delete_item<'py>(
         &self,
@@ -298,18 +311,30 @@ impl ContainerClient {
         item: String,
         partition_key: PyObject,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<&'py PyDict> {
+    ) -> PyResult<CosmosResponseDiagnostics> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
-        let pk = self.python_to_partition_key(py, partition_key)?;
+        let retry_policy = self.retry_policy.clone();
+
+        let pk = crate::utils::python_to_partition_key(py, partition_key.as_ref(py))?;
         let item_id = item.clone();
-        
-        let header_map = TOKIO_RUNTIME.block_on(async move {
-            let response = container.delete_item(pk, &item_id, None)
-                .await
-                .map_err(map_error)?;
+        let if_match = self.extract_etag(_kwargs)?;
+
+        let (mut header_map, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let options = ItemOptions {
+                if_match,
+                ..Default::default()
+            };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let item_id = &item_id;
+                let options = options.clone();
+                async move { container.delete_item(pk, item_id, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
 
             // Extract headers into a HashMap
             let mut headers: HashMap<String, String> = HashMap::new();
@@ -317,125 +342,398 @@ impl ContainerClient {
                 headers.insert(name.as_str().to_string(), value.as_str().to_string());
             }
 
-            Ok::<_, PyErr>(headers)
-        })?;
+            Ok::<_, PyErr>((headers, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        Ok(CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge))
+    }
+
+    /// Execute a transactional batch of operations scoped to a single partition key (up to
+    /// 100 operations). Each entry in `operations` is either a dict (see
+    /// `parse_batch_operations`) or a tuple like `("create", body)`, `("upsert", body)`,
+    /// `("replace", item_id, body)`, `("patch", item_id, patch_ops)`, `("delete", item_id)`.
+    /// The whole batch commits or rolls back together: on a failed batch, the response comes
+    /// back with every operation at 424 "dependency failed" except the one that actually
+    /// failed, and we raise a mapped exception naming that operation's index and status.
+    /// Returns tuple of (list_of_(status_code, resource_or_None), diagnostics)
+    #[pyo3(signature = (partition_key, operations, **_kwargs))]
+    pub fn execute_item_batch<'py>(
+        &self,
+        py: Python<'py>,
+        partition_key: PyObject,
+        operations: &PyList,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<(Vec<&'py PyTuple>, CosmosResponseDiagnostics)> {
+        let container = self.cosmos_client
+            .database_client(&self.database_id)
+            .container_client(&self.container_id);
+        let retry_policy = self.retry_policy.clone();
+
+        let pk = crate::utils::python_to_partition_key(py, partition_key.as_ref(py))?;
+        let parsed_operations = self.parse_batch_operations(py, operations)?;
 
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
+        let (mut header_map, results, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let options = ItemOptions {
+                enable_content_response_on_write: true,
+                ..Default::default()
+            };
+
+            // A transactional batch isn't idempotent to blindly retry as a whole (rerunning a
+            // batch that actually committed would duplicate creates), so retries here only
+            // cover the request failing outright (429/5xx/connection) before Cosmos executed
+            // it - a batch that executed and came back with a per-operation failure is
+            // surfaced to the caller below, not retried.
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let parsed_operations = parsed_operations.clone();
+                let options = options.clone();
+                async move { container.execute_batch(pk, parsed_operations, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut headers: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                headers.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            let batch_response: BatchResponse = response.into_body().json()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize batch response: {}", e)))?;
+
+            // A successful atomic batch has every operation at a 2xx status - including 204
+            // No Content, which is what delete sub-operations return. A rolled-back batch has
+            // exactly one operation at a non-2xx, non-424 status - that's the one that
+            // actually caused the rollback, with the rest coming back as 424 "dependency failed".
+            if let Some((index, failed)) = batch_response.results.iter().enumerate()
+                .find(|(_, r)| r.status_code >= 300 && r.status_code != 424)
+            {
+                return Err(map_error(typespec::error::Error::message(
+                    typespec::error::ErrorKind::HttpResponse {
+                        status: failed.status_code,
+                        error_code: failed.sub_status_code.map(|s| s.to_string()),
+                    },
+                    format!("Batch operation at index {} failed with status {}", index, failed.status_code),
+                )));
+            }
+
+            Ok::<_, PyErr>((headers, batch_response.results, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+        let mut py_results = Vec::new();
+        for result in results {
+            let resource: PyObject = match result.resource_body {
+                Some(ref body) => crate::utils::json_value_to_pyobject(py, body)?,
+                None => py.None(),
+            };
+            py_results.push(PyTuple::new(py, &[result.status_code.to_object(py), resource]));
         }
-        Ok(headers)
+
+        Ok((py_results, diagnostics))
     }
 
     /// Query items with SQL
-    /// Returns tuple of (list_of_dicts, headers_dict)
+    /// Supports `max_item_count` and `continuation_token` kwargs for paging; when
+    /// `partition_key` is omitted the query fans out across physical partitions and the
+    /// per-partition continuation state is packed into a single opaque `continuation_token`.
+    /// Returns tuple of (list_of_dicts, continuation_token, diagnostics)
+    /// The diagnostics carry the retry count and the request charge of failed attempts only;
+    /// the pages' own response headers and request charges are not included.
     #[pyo3(signature = (query, **kwargs))]
     pub fn query_items<'py>(
         &self,
         py: Python<'py>,
         query: String,
         kwargs: Option<&PyDict>,
-    ) -> PyResult<(Vec<&'py PyDict>, &'py PyDict)> {
+    ) -> PyResult<(Vec<&'py PyDict>, Option<String>, CosmosResponseDiagnostics)> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
+        let retry_policy = self.retry_policy.clone();
+
         // Extract partition_key from kwargs if provided
         let partition_key_opt = if let Some(kw) = kwargs {
             if let Ok(Some(pk)) = kw.get_item("partition_key") {
-                Some(self.python_to_partition_key(py, pk.into())?)
+                Some(crate::utils::python_to_partition_key(py, pk)?)
             } else {
                 None
             }
         } else {
             None
         };
-        
-        let items = TOKIO_RUNTIME.block_on(async move {
-            let mut result = Vec::new();
-            
-            // If no partition key is provided, we need to do a cross-partition query
-            // For now, if partition_key is not specified, return error asking for it
-            let pk = partition_key_opt.ok_or_else(|| {
-                PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                    "partition_key is required for queries. For cross-partition queries, this will be supported in a future update."
-                )
-            })?;
-            
-            let mut stream = container.query_items::<Value>(&query, pk, None).map_err(map_error)?;
-            
+
+        let max_item_count = self.extract_max_item_count(kwargs)?;
+        let incoming_continuation = self.extract_continuation_token(kwargs)?;
+
+        let (items, continuation_state, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
             use futures::StreamExt;
-            while let Some(response) = stream.next().await {
-                match response {
-                    Ok(item) => {
-                        result.push(item);
-                    },
-                    Err(e) => return Err(map_error(e)),
+            let mut stats = retry::RetryStats::default();
+
+            if let Some(pk) = partition_key_opt {
+                // Single-partition query: thread the continuation token straight through.
+                let options = QueryOptions {
+                    max_item_count,
+                    continuation_token: incoming_continuation,
+                    ..Default::default()
+                };
+                let mut stream = container.query_items::<Value>(&query, pk, Some(options)).map_err(map_error)?;
+
+                let mut result = Vec::new();
+                let mut continuation = None;
+                let (page, page_stats) = retry_policy.execute(|| {
+                    let stream = &mut stream;
+                    async move { stream.next().await.transpose() }
+                }).await;
+                stats.retry_count += page_stats.retry_count;
+                stats.request_charge += page_stats.request_charge;
+                if let Some(page) = page.map_err(map_error)? {
+                    continuation = page.continuation_token.clone();
+                    result.extend(page.items);
+                }
+
+                Ok::<_, PyErr>((result, continuation, stats))
+            } else {
+                // Cross-partition query: fan the SQL query across physical partition key
+                // ranges and merge one page from each, carrying per-range continuation
+                // state as a composite token so the caller can resume the whole fan-out.
+                let ranges = container.read_partition_key_ranges(None)
+                    .await
+                    .map_err(map_error)?;
+
+                let mut per_range_continuation: HashMap<String, String> = incoming_continuation
+                    .as_deref()
+                    .and_then(|token| serde_json::from_str(token).ok())
+                    .unwrap_or_default();
+                let resuming = !per_range_continuation.is_empty();
+
+                let mut result = Vec::new();
+                for range in ranges {
+                    let range_continuation = per_range_continuation.remove(&range.id);
+                    if resuming && range_continuation.is_none() {
+                        // Drained on an earlier page, so it has nothing left to resume.
+                        continue;
+                    }
+                    let options = QueryOptions {
+                        max_item_count,
+                        continuation_token: range_continuation,
+                        ..Default::default()
+                    };
+
+                    let mut stream = container
+                        .query_items_in_range::<Value>(&query, &range, Some(options))
+                        .map_err(map_error)?;
+
+                    let (page, page_stats) = retry_policy.execute(|| {
+                        let stream = &mut stream;
+                        async move { stream.next().await.transpose() }
+                    }).await;
+                    stats.retry_count += page_stats.retry_count;
+                    stats.request_charge += page_stats.request_charge;
+
+                    if let Some(page) = page.map_err(map_error)? {
+                        if let Some(token) = page.continuation_token {
+                            per_range_continuation.insert(range.id.clone(), token);
+                        }
+                        result.extend(page.items);
+                    }
                 }
+
+                let continuation = if per_range_continuation.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::to_string(&per_range_continuation)
+                        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to encode continuation token: {}", e)))?)
+                };
+
+                Ok::<_, PyErr>((result, continuation, stats))
             }
-            
-            Ok::<_, PyErr>(result)
-        })?;
+        }))?;
 
         let mut py_items = Vec::new();
         for item in items {
-            let json_str = serde_json::to_string(&item)
-                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON error: {}", e)))?;
-            
-            let json_module = py.import("json")?;
-            let py_dict = json_module.call_method1("loads", (json_str,))?;
-            py_items.push(py_dict.extract()?);
+            py_items.push(crate::utils::json_value_to_py_dict(py, &item)?);
         }
 
-        let headers = empty_headers_dict(py);
-        Ok((py_items, headers))
+        let mut header_map = HashMap::new();
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+        Ok((py_items, continuation_state, diagnostics))
     }
 
-    /// Patch an item
-    #[pyo3(signature = (_item, _partition_key, _patch_operations, **_kwargs))]
+    /// Read the change feed for this container, starting from a continuation token (an
+    /// opaque ETag/LSN watermark) or a start time. Returns an empty list and a
+    /// "no new changes" signal in the diagnostics headers when nothing has changed since
+    /// `continuation`, so callers can repeatedly pass the returned continuation back in
+    /// to get only new/updated documents.
+    /// Returns tuple of (list_of_changed_items, diagnostics)
+    #[pyo3(signature = (partition_key=None, continuation=None, start_time=None, **_kwargs))]
+    pub fn query_items_change_feed<'py>(
+        &self,
+        py: Python<'py>,
+        partition_key: Option<PyObject>,
+        continuation: Option<String>,
+        start_time: Option<String>,
+        _kwargs: Option<&PyDict>,
+    ) -> PyResult<(Vec<&'py PyDict>, CosmosResponseDiagnostics)> {
+        let container = self.cosmos_client
+            .database_client(&self.database_id)
+            .container_client(&self.container_id);
+        let retry_policy = self.retry_policy.clone();
+
+        let pk_opt = partition_key
+            .map(|pk| crate::utils::python_to_partition_key(py, pk.as_ref(py)))
+            .transpose()?;
+
+        let options = ChangeFeedOptions {
+            continuation: continuation.clone(),
+            start_time,
+            ..Default::default()
+        };
+
+        let (mut header_map, items, has_changes, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk_opt = pk_opt.clone();
+                let options = options.clone();
+                async move { container.query_change_feed::<Value>(pk_opt, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut headers: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                headers.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            // A 304 Not Modified response has no body at all, so only parse one out when
+            // there's actually new content to read.
+            let has_changes = response.status() != azure_core::http::StatusCode::NotModified;
+            let items = if has_changes {
+                let feed: ChangeFeedPage<Value> = response.into_body().json()
+                    .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize change feed response: {}", e)))?;
+                feed.items
+            } else {
+                Vec::new()
+            };
+
+            Ok::<_, PyErr>((headers, items, has_changes, stats))
+        }))?;
+
+        header_map.insert("has_more_changes".to_string(), has_changes.to_string());
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+        if !has_changes {
+            return Ok((Vec::new(), diagnostics));
+        }
+
+        let mut py_items = Vec::new();
+        for item in items {
+            let py_dict: &PyDict = crate::utils::json_value_to_py_dict(py, &item)?;
+            py_items.push(py_dict);
+        }
+
+        Ok((py_items, diagnostics))
+    }
+
+    /// Patch an item with the JSON Patch operation set (add/set/replace/remove/incr/move)
+    /// Returns tuple of (item_dict, diagnostics)
+    #[pyo3(signature = (item, partition_key, patch_operations, **_kwargs))]
     pub fn patch_item<'py>(
         &self,
-        _py: Python<'py>,
-        _item: String,
-        _partition_key: PyObject,
-        _patch_operations: &PyList,
+        py: Python<'py>,
+        item: String,
+        partition_key: PyObject,
+        patch_operations: &PyList,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
-        Err(PyErr::new::<pyo3::exceptions::PyNotImplementedError, _>(
-            "patch_item is not yet implemented"
-        ))
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
+        let container = self.cosmos_client
+            .database_client(&self.database_id)
+            .container_client(&self.container_id);
+        let retry_policy = self.retry_policy.clone();
+
+        let pk = crate::utils::python_to_partition_key(py, partition_key.as_ref(py))?;
+        let item_id = item.clone();
+        let patch_doc = self.parse_patch_operations(py, patch_operations)?;
+        let if_match = self.extract_etag(_kwargs)?;
+
+        let (mut header_map, value, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let options = ItemOptions {
+                enable_content_response_on_write: true,
+                if_match,
+                ..Default::default()
+            };
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                let pk = pk.clone();
+                let item_id = &item_id;
+                let patch_doc = patch_doc.clone();
+                let options = options.clone();
+                async move { container.patch_item(pk, item_id, patch_doc, Some(options)).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut headers: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                headers.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            let body_value = response.into_body().json::<Value>()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
+
+            Ok::<_, PyErr>((headers, body_value, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+        let py_dict: &PyDict = crate::utils::json_value_to_py_dict(py, &value)?;
+        Ok((py_dict, diagnostics))
     }
 
     /// Read container properties
-    /// Returns tuple of (properties_dict, headers_dict)
+    /// Returns tuple of (properties_dict, diagnostics)
     #[pyo3(signature = (**_kwargs))]
     pub fn read<'py>(
         &self,
         py: Python<'py>,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
         let dict = PyDict::new(py);
         dict.set_item("id", &self.container_id)?;
-        let headers = empty_headers_dict(py);
-        Ok((dict, headers))
+        let diagnostics = CosmosResponseDiagnostics::from_headers(HashMap::new(), 0.0);
+        Ok((dict, diagnostics))
     }
 
     /// Delete this container
-    /// Returns headers_dict
+    /// Returns diagnostics
     #[pyo3(signature = (**_kwargs))]
-    pub fn delete<'py>(&self, py: Python<'py>, _kwargs: Option<&PyDict>) -> PyResult<&'py PyDict> {
+    pub fn delete(&self, py: Python<'_>, _kwargs: Option<&PyDict>) -> PyResult<CosmosResponseDiagnostics> {
         let container = self.cosmos_client
             .database_client(&self.database_id)
             .container_client(&self.container_id);
-        
-        TOKIO_RUNTIME.block_on(async move {
-            container.delete(None)
-                .await
-                .map_err(map_error)
-        })?;
-
-        Ok(empty_headers_dict(py))
+        let retry_policy = self.retry_policy.clone();
+
+        let (mut header_map, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                async move { container.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut headers: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                headers.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            Ok::<_, PyErr>((headers, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        Ok(CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge))
     }
 
     #[getter]
@@ -446,62 +744,34 @@ impl ContainerClient {
 
 // Helper methods for ContainerClient
 impl ContainerClient {
-    fn python_to_partition_key(&self, py: Python, pk: PyObject) -> PyResult<RustPartitionKey> {
-        if let Ok(s) = pk.extract::<String>(py) {
-            Ok(RustPartitionKey::from(s))
-        } else if let Ok(i) = pk.extract::<i64>(py) {
-            Ok(RustPartitionKey::from(i))
-        } else if let Ok(f) = pk.extract::<f64>(py) {
-            Ok(RustPartitionKey::from(f))
-        } else {
-            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-                "Partition key must be string, int, or float"
-            ))
-        }
+    /// Delegates to the shared `utils::extract_partition_key_from_body`, which both this
+    /// client and `async_client.rs` use so the lookup order (kwarg, then partition key
+    /// path(s), then common field names) stays in one place.
+    fn extract_partition_key(&self, py: Python, body: &PyDict, kwargs: Option<&PyDict>) -> PyResult<RustPartitionKey> {
+        crate::utils::extract_partition_key_from_body(py, body, &self.partition_key_paths, kwargs)
     }
 
-    fn extract_partition_key(&self, py: Python, body: &PyDict, kwargs: Option<&PyDict>) -> PyResult<RustPartitionKey> {
-        // Try to get partition_key from kwargs first
-        if let Some(kw) = kwargs {
-            if let Ok(Some(pk)) = kw.get_item("partition_key") {
-                return self.python_to_partition_key(py, pk.into());
-            }
-        }
-        
-        // If we have a partition key path, use it to extract the value from the body
-        if let Some(ref pk_path) = self.partition_key_path {
-            // Convert path like "/pk" to field name "pk"
-            let field_name = pk_path.trim_start_matches('/');
-            if let Ok(Some(value)) = body.get_item(field_name) {
-                return self.python_to_partition_key(py, value.into());
-            }
-        }
+    fn parse_patch_operations(&self, py: Python, patch_operations: &PyList) -> PyResult<PatchDocument> {
+        crate::utils::parse_patch_operations(py, patch_operations)
+    }
 
-        // Fallback: try common partition key field names
-        // Note: "pk" should be checked before "id" since "pk" is more commonly the partition key
-        let common_pk_fields = ["pk", "partitionKey", "category", "type", "tenantId", "id"];
-        for field in &common_pk_fields {
-            if let Ok(Some(value)) = body.get_item(field) {
-                return self.python_to_partition_key(py, value.into());
-            }
-        }
-        
-        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-            "Partition key not found in body or kwargs"
-        ))
+    fn extract_etag(&self, kwargs: Option<&PyDict>) -> PyResult<Option<String>> {
+        crate::utils::extract_etag(kwargs)
     }
-    
-    fn extract_partition_key_from_kwargs(&self, kwargs: Option<&PyDict>) -> PyResult<RustPartitionKey> {
-        Python::with_gil(|py| {
-            if let Some(kw) = kwargs {
-                if let Ok(Some(pk)) = kw.get_item("partition_key") {
-                    return self.python_to_partition_key(py, pk.into());
-                }
-            }
-            
-            Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
-                "Partition key must be provided in kwargs when body is a JSON string"
-            ))
-        })
+
+    fn extract_max_item_count(&self, kwargs: Option<&PyDict>) -> PyResult<Option<i32>> {
+        crate::utils::extract_max_item_count(kwargs)
+    }
+
+    fn extract_continuation_token(&self, kwargs: Option<&PyDict>) -> PyResult<Option<String>> {
+        crate::utils::extract_continuation_token(kwargs)
+    }
+
+    fn parse_batch_operations(&self, py: Python, batch_operations: &PyList) -> PyResult<Vec<BatchOperation>> {
+        crate::utils::parse_batch_operations(py, batch_operations)
+    }
+
+    fn extract_partition_key_from_kwargs(&self, py: Python, kwargs: Option<&PyDict>) -> PyResult<RustPartitionKey> {
+        crate::utils::extract_partition_key_from_kwargs(py, kwargs)
     }
 }
@@ -1,11 +1,13 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
-use azure_data_cosmos::{CosmosClient as RustCosmosClient, models::{ContainerProperties, PartitionKeyDefinition}};
+use azure_data_cosmos::{CosmosClient as RustCosmosClient, QueryOptions, models::{ContainerProperties, PartitionKeyDefinition}};
 use std::sync::Arc;
 use std::collections::HashMap;
 use crate::container::ContainerClient;
+use crate::diagnostics::CosmosResponseDiagnostics;
 use crate::exceptions::map_error;
-use crate::utils::empty_headers_dict;
+use crate::iterator::{Page, QueryIterator};
+use crate::retry::RetryPolicy;
 use once_cell::sync::Lazy;
 use tokio::runtime::Runtime;
 use serde_json::Value;
@@ -21,13 +23,15 @@ static TOKIO_RUNTIME: Lazy<Runtime> = Lazy::new(|| {
 pub struct DatabaseClient {
     cosmos_client: Arc<RustCosmosClient>,
     database_id: String,
+    retry_policy: Arc<RetryPolicy>,
 }
 
 impl DatabaseClient {
-    pub fn new(cosmos_client: Arc<RustCosmosClient>, database_id: String) -> Self {
+    pub fn new(cosmos_client: Arc<RustCosmosClient>, database_id: String, retry_policy: Arc<RetryPolicy>) -> Self {
         Self {
             cosmos_client,
             database_id,
+            retry_policy,
         }
     }
 }
@@ -35,60 +39,77 @@ impl DatabaseClient {
 #[pymethods]
 impl DatabaseClient {
     /// Create a new container
-    /// Returns tuple of (ContainerClient, headers_dict)
+    /// Returns tuple of (ContainerClient, diagnostics)
     #[pyo3(signature = (id, partition_key, **_kwargs))]
-    pub fn create_container<'py>(
+    pub fn create_container(
         &self,
-        py: Python<'py>,
+        py: Python<'_>,
         id: String,
         partition_key: &PyDict,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(ContainerClient, &'py PyDict)> {
+    ) -> PyResult<(ContainerClient, CosmosResponseDiagnostics)> {
         let db_client = self.cosmos_client.database_client(&self.database_id);
-        
-        // Extract partition key path
+        let retry_policy = self.retry_policy.clone();
+
+        // Extract partition key path(s); more than one path means a hierarchical
+        // (subpartitioned) container, which Cosmos models as kind "MultiHash".
         let paths = partition_key.get_item("paths")?
             .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyKeyError, _>("partition_key must have 'paths'"))?;
         let path_list = paths.extract::<Vec<String>>()?;
-        let partition_key_path = path_list.get(0)
-            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("partition_key paths cannot be empty"))?
-            .clone();
-        
+        if path_list.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>("partition_key paths cannot be empty"));
+        }
+
         let container_id = id.clone();
-        let pk_path_clone = partition_key_path.clone();
-        
-        let header_map = TOKIO_RUNTIME.block_on(async move {
+        let partition_key_definition = if path_list.len() > 1 {
+            PartitionKeyDefinition {
+                paths: path_list.iter().cloned().map(Into::into).collect(),
+                kind: "MultiHash".into(),
+                ..Default::default()
+            }
+        } else {
+            PartitionKeyDefinition::from(path_list[0].clone())
+        };
+        let partition_key_paths = path_list.clone();
+
+        // Release the GIL for the blocking call: a token credential's get_token runs on a
+        // separate thread via spawn_blocking and needs to reacquire the GIL itself, which
+        // would deadlock forever against this thread if it held the GIL through block_on.
+        let (mut header_map, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
             let props = ContainerProperties {
                 id: container_id.into(),
-                partition_key: PartitionKeyDefinition::from(pk_path_clone),
+                partition_key: partition_key_definition,
                 ..Default::default()
             };
-            let response = db_client.create_container(props, None)
-                .await
-                .map_err(map_error)?;
-            
+
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = &db_client;
+                let props = props.clone();
+                async move { db_client.create_container(props, None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
             // Extract headers into a HashMap
             let mut headers: HashMap<String, String> = HashMap::new();
             for (name, value) in response.headers().iter() {
                 headers.insert(name.as_str().to_string(), value.as_str().to_string());
             }
-            
-            Ok::<_, PyErr>(headers)
-        })?;
-
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
-        
-        // Use with_partition_key_path to pass the partition key path to the container client
-        Ok((ContainerClient::with_partition_key_path(
+
+            Ok::<_, PyErr>((headers, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+        // Pass the partition key path(s) through so the container client can extract
+        // the key from item bodies when callers don't pass one explicitly.
+        Ok((ContainerClient::with_partition_key_paths(
             self.cosmos_client.clone(),
             self.database_id.clone(),
             id,
-            partition_key_path,
-        ), headers))
+            partition_key_paths,
+            self.retry_policy.clone(),
+        ), diagnostics))
     }
 
     /// Get a container client
@@ -97,134 +118,163 @@ impl DatabaseClient {
             self.cosmos_client.clone(),
             self.database_id.clone(),
             container_id,
+            self.retry_policy.clone(),
         ))
     }
 
     /// Delete a container
-    /// Returns headers_dict
+    /// Returns diagnostics
     #[pyo3(signature = (container_id, **_kwargs))]
-    pub fn delete_container<'py>(
+    pub fn delete_container(
         &self,
-        py: Python<'py>,
+        py: Python<'_>,
         container_id: String,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<&'py PyDict> {
+    ) -> PyResult<CosmosResponseDiagnostics> {
         let db_client = self.cosmos_client.database_client(&self.database_id);
-        
-        let header_map = TOKIO_RUNTIME.block_on(async move {
+        let retry_policy = self.retry_policy.clone();
+
+        let (mut header_map, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
             let container = db_client.container_client(&container_id);
-            let response = container.delete(None)
-                .await
-                .map_err(map_error)?;
-            
+
+            let (result, stats) = retry_policy.execute(|| {
+                let container = &container;
+                async move { container.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
             // Extract headers into a HashMap
             let mut headers: HashMap<String, String> = HashMap::new();
             for (name, value) in response.headers().iter() {
                 headers.insert(name.as_str().to_string(), value.as_str().to_string());
             }
-            
-            Ok::<_, PyErr>(headers)
-        })?;
-
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
-        Ok(headers)
+
+            Ok::<_, PyErr>((headers, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        Ok(CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge))
     }
 
     /// Read database properties
-    /// Returns tuple of (properties_dict, headers_dict)
+    /// Returns tuple of (properties_dict, diagnostics)
     #[pyo3(signature = (**_kwargs))]
     pub fn read<'py>(
         &self,
         py: Python<'py>,
         _kwargs: Option<&PyDict>,
-    ) -> PyResult<(&'py PyDict, &'py PyDict)> {
+    ) -> PyResult<(&'py PyDict, CosmosResponseDiagnostics)> {
         let db_client = self.cosmos_client.database_client(&self.database_id);
-        
-        let (header_map, value) = TOKIO_RUNTIME.block_on(async move {
-            let response = db_client.read(None)
-                .await
-                .map_err(map_error)?;
-            
+        let retry_policy = self.retry_policy.clone();
+
+        let (mut header_map, value, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = &db_client;
+                async move { db_client.read(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
             // Extract headers into a HashMap before consuming the body
             let mut headers: HashMap<String, String> = HashMap::new();
             for (name, value) in response.headers().iter() {
                 headers.insert(name.as_str().to_string(), value.as_str().to_string());
             }
-            
+
             // Get the body as JSON
             let body_value = response.into_body().json::<Value>()
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to deserialize response: {}", e)))?;
-            
-            Ok::<_, PyErr>((headers, body_value))
-        })?;
-
-        // Convert headers to Python dict
-        let headers = PyDict::new(py);
-        for (key, value) in header_map.iter() {
-            headers.set_item(key, value)?;
-        }
-        
-        let json_str = serde_json::to_string(&value)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON error: {}", e)))?;
-        
-        let json_module = py.import("json")?;
-        let dict: &PyDict = json_module.call_method1("loads", (json_str,))?.extract()?;
-        Ok((dict, headers))
+
+            Ok::<_, PyErr>((headers, body_value, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        let diagnostics = CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge);
+
+        let dict: &PyDict = crate::utils::json_value_to_py_dict(py, &value)?;
+        Ok((dict, diagnostics))
     }
 
     /// List all containers
-    /// Returns tuple of (list_of_dicts, headers_dict)
-    #[pyo3(signature = (**_kwargs))]
-    pub fn list_containers<'py>(
-        &self,
-        py: Python<'py>,
-        _kwargs: Option<&PyDict>,
-    ) -> PyResult<(Vec<&'py PyDict>, &'py PyDict)> {
+    /// Returns a `QueryIterator` that pulls one page at a time through the shared Tokio
+    /// runtime rather than draining the whole container list up front. Pass
+    /// `max_item_count` to cap page size and `continuation_token` to resume a prior listing.
+    #[pyo3(signature = (**kwargs))]
+    pub fn list_containers(&self, kwargs: Option<&PyDict>) -> PyResult<QueryIterator> {
         let db_client = self.cosmos_client.database_client(&self.database_id);
-        
-        let containers = TOKIO_RUNTIME.block_on(async move {
-            let mut result = Vec::new();
-            let mut stream = db_client.query_containers("SELECT * FROM containers", None).map_err(map_error)?;
-            
+        let retry_policy = self.retry_policy.clone();
+        let max_item_count = crate::utils::extract_max_item_count(kwargs)?;
+        let continuation_token = crate::utils::extract_continuation_token(kwargs)?;
+
+        let options = QueryOptions {
+            max_item_count,
+            continuation_token,
+            ..Default::default()
+        };
+        let mut stream = db_client.query_containers::<Value>("SELECT * FROM containers", Some(options))
+            .map_err(map_error)?;
+
+        let fetch_next: Box<dyn FnMut() -> PyResult<Option<Page>> + Send> = Box::new(move || {
             use futures::StreamExt;
-            while let Some(response) = stream.next().await {
-                match response {
-                    Ok(container) => result.push(container),
-                    Err(e) => return Err(map_error(e)),
+            TOKIO_RUNTIME.block_on(async {
+                let (result, stats) = retry_policy.execute(|| {
+                    let stream = &mut stream;
+                    async move {
+                        match stream.next().await {
+                            Some(page_result) => page_result.map(Some),
+                            None => Ok(None),
+                        }
+                    }
+                }).await;
+
+                match result {
+                    Ok(Some(page)) => {
+                        let mut headers: HashMap<String, String> = HashMap::new();
+                        if let Some(token) = &page.continuation_token {
+                            headers.insert("x-ms-continuation".to_string(), token.clone());
+                        }
+                        headers.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+                        // Only the charge of attempts that failed before this page; the
+                        // page's own request charge is not included.
+                        headers.insert("x-ms-request-charge".to_string(), stats.request_charge.to_string());
+                        Ok(Some(Page {
+                            items: page.items,
+                            continuation_token: page.continuation_token,
+                            headers,
+                        }))
+                    }
+                    Ok(None) => Ok(None),
+                    Err(e) => Err(map_error(e)),
                 }
-            }
-            
-            Ok::<_, PyErr>(result)
-        })?;
-
-        let mut py_containers = Vec::new();
-        for container in containers {
-            let dict = PyDict::new(py);
-            dict.set_item("id", format!("{:?}", container))?;
-            py_containers.push(dict);
-        }
+            })
+        });
 
-        let headers = empty_headers_dict(py);
-        Ok((py_containers, headers))
+        Ok(QueryIterator::new(fetch_next))
     }
 
     /// Delete this database
-    /// Returns headers_dict
+    /// Returns diagnostics
     #[pyo3(signature = (**_kwargs))]
-    pub fn delete<'py>(&self, py: Python<'py>, _kwargs: Option<&PyDict>) -> PyResult<&'py PyDict> {
+    pub fn delete(&self, py: Python<'_>, _kwargs: Option<&PyDict>) -> PyResult<CosmosResponseDiagnostics> {
         let db_client = self.cosmos_client.database_client(&self.database_id);
-        
-        TOKIO_RUNTIME.block_on(async move {
-            db_client.delete(None)
-                .await
-                .map_err(map_error)
-        })?;
-
-        Ok(empty_headers_dict(py))
+        let retry_policy = self.retry_policy.clone();
+
+        let (mut header_map, stats) = py.allow_threads(|| TOKIO_RUNTIME.block_on(async move {
+            let (result, stats) = retry_policy.execute(|| {
+                let db_client = &db_client;
+                async move { db_client.delete(None).await }
+            }).await;
+            let response = result.map_err(map_error)?;
+
+            let mut headers: HashMap<String, String> = HashMap::new();
+            for (name, value) in response.headers().iter() {
+                headers.insert(name.as_str().to_string(), value.as_str().to_string());
+            }
+
+            Ok::<_, PyErr>((headers, stats))
+        }))?;
+
+        header_map.insert("x-ms-retry-count".to_string(), stats.retry_count.to_string());
+        Ok(CosmosResponseDiagnostics::from_headers(header_map, stats.request_charge))
     }
 
     #[getter]
@@ -0,0 +1,57 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+
+/// Response metadata surfaced alongside every Cosmos operation. `request_charge`/
+/// `activity_id`/`session_token`/`resource_usage` are the well-known diagnostic headers
+/// broken out as typed fields for cost accounting and support correlation; `headers` exposes
+/// the header set the diagnostics were built from for anything else.
+#[pyclass]
+#[derive(Debug, Clone, Default)]
+pub struct CosmosResponseDiagnostics {
+    #[pyo3(get)]
+    pub request_charge: f64,
+    #[pyo3(get)]
+    pub activity_id: Option<String>,
+    #[pyo3(get)]
+    pub session_token: Option<String>,
+    #[pyo3(get)]
+    pub resource_usage: Option<String>,
+    #[pyo3(get)]
+    pub retry_count: u32,
+    raw_headers: HashMap<String, String>,
+}
+
+#[pymethods]
+impl CosmosResponseDiagnostics {
+    /// The header set these diagnostics were built from, including the ones already broken
+    /// out above. For an operation with a single response this is that response's headers
+    /// plus the client-added `x-ms-retry-count` (and `has_more_changes` for the change feed);
+    /// query results and listing pages carry only entries the client adds itself, and a
+    /// container `read` carries none.
+    #[getter]
+    fn headers<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        crate::utils::headers_to_py_dict(py, &self.raw_headers)
+    }
+}
+
+impl CosmosResponseDiagnostics {
+    /// Build diagnostics from a response's raw headers. `extra_request_charge` adds on the
+    /// RU cost consumed by retried attempts that failed before this response succeeded
+    /// (`RetryStats::request_charge`), since that cost never shows up in the final response's
+    /// own `x-ms-request-charge` header.
+    pub fn from_headers(headers: HashMap<String, String>, extra_request_charge: f64) -> Self {
+        let find = |name: &str| headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone());
+
+        Self {
+            request_charge: find("x-ms-request-charge").and_then(|v| v.parse().ok()).unwrap_or(0.0) + extra_request_charge,
+            activity_id: find("x-ms-activity-id"),
+            session_token: find("x-ms-session-token"),
+            resource_usage: find("x-ms-resource-usage"),
+            retry_count: find("x-ms-retry-count").and_then(|v| v.parse().ok()).unwrap_or(0),
+            raw_headers: headers,
+        }
+    }
+}
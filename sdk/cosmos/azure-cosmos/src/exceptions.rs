@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
 use pyo3::types::PyModule;
-use typespec::error::Error as TypeSpecError;
+use typespec::error::{Error as TypeSpecError, ErrorKind};
+use azure_core::http::headers::HeaderName;
 
 /// Get the Python exception classes from azure.cosmos.exceptions module
 fn get_cosmos_exceptions(py: Python) -> PyResult<(PyObject, PyObject, PyObject, PyObject)> {
@@ -12,10 +13,46 @@ fn get_cosmos_exceptions(py: Python) -> PyResult<(PyObject, PyObject, PyObject,
     Ok((http_error, not_found_error, exists_error, precondition_error))
 }
 
-/// Extract HTTP status code from error message
-fn extract_status_code(error_msg: &str) -> Option<u16> {
-    // Look for patterns like "StatusCode: 409" or "status_code=409" or just "409:" at the start
-    // Also check for explicit HTTP status code patterns
+/// Structured information pulled off the underlying HTTP response, when the error
+/// actually came from one (as opposed to a transport-level failure).
+struct HttpErrorInfo {
+    status: u16,
+    sub_status: Option<u32>,
+    retry_after_ms: Option<u64>,
+}
+
+/// Read the status code, sub-status, and `x-ms-retry-after-ms` structurally off the
+/// `TypeSpecError`'s underlying HTTP response, rather than scraping the formatted
+/// error string for a few hardcoded substrings.
+fn structured_error_info(err: &TypeSpecError) -> Option<HttpErrorInfo> {
+    match err.kind() {
+        ErrorKind::HttpResponse { status, error_code } => {
+            let headers = err.http_response_headers();
+            // Real responses carry the sub-status in the x-ms-substatus header; synthetic
+            // errors we construct ourselves (e.g. a failed batch sub-operation) have no
+            // headers at all, so fall back to the error_code we packed it into.
+            let sub_status = headers
+                .and_then(|h| h.get_optional_str(&HeaderName::from_static("x-ms-substatus")))
+                .and_then(|s| s.parse().ok())
+                .or_else(|| error_code.as_ref().and_then(|s| s.parse().ok()));
+            let retry_after_ms = headers
+                .and_then(|h| h.get_optional_str(&HeaderName::from_static("x-ms-retry-after-ms")))
+                .and_then(|s| s.parse().ok());
+
+            Some(HttpErrorInfo {
+                status: *status as u16,
+                sub_status,
+                retry_after_ms,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Last-resort fallback: scrape the formatted error message for a few well-known
+/// status code markers. Only used when the error doesn't carry a structured HTTP
+/// response (e.g. it was constructed from a raw transport failure).
+fn extract_status_code_from_message(error_msg: &str) -> Option<u16> {
     if error_msg.contains("StatusCode: 409") || error_msg.contains("\"code\":\"Conflict\"") {
         return Some(409);
     }
@@ -25,49 +62,56 @@ fn extract_status_code(error_msg: &str) -> Option<u16> {
     if error_msg.contains("StatusCode: 412") || error_msg.contains("\"code\":\"PreconditionFailed\"") {
         return Some(412);
     }
+    if error_msg.contains("StatusCode: 429") || error_msg.contains("\"code\":\"TooManyRequests\"") {
+        return Some(429);
+    }
+    if error_msg.contains("StatusCode: 408") {
+        return Some(408);
+    }
+    if error_msg.contains("StatusCode: 503") {
+        return Some(503);
+    }
     None
 }
 
 pub fn map_error(err: TypeSpecError) -> PyErr {
-    // Map Rust SDK errors to Python exceptions
     let error_msg = format!("{}", err);
-    
+    let info = structured_error_info(&err);
+    let status_code = info.as_ref().map(|i| i.status)
+        .or_else(|| extract_status_code_from_message(&error_msg));
+    let sub_status = info.as_ref().and_then(|i| i.sub_status);
+    let retry_after_ms = info.and_then(|i| i.retry_after_ms);
+
     Python::with_gil(|py| {
-        // Try to get the actual Python exception classes
         match get_cosmos_exceptions(py) {
             Ok((http_error, not_found_error, exists_error, precondition_error)) => {
-                // Extract status code from error message
-                let status_code = extract_status_code(&error_msg);
+                let exc_result = match status_code {
+                    Some(409) => exists_error.call1(py, (409i32, error_msg.clone())),
+                    Some(404) => not_found_error.call1(py, (404i32, error_msg.clone())),
+                    Some(412) => precondition_error.call1(py, (412i32, error_msg.clone())),
+                    Some(429) => http_error.call1(py, (429i32, error_msg.clone())),
+                    Some(408) => http_error.call1(py, (408i32, error_msg.clone())),
+                    Some(503) => http_error.call1(py, (503i32, error_msg.clone())),
+                    Some(other) => http_error.call1(py, (other as i32, error_msg.clone())),
+                    None => http_error.call1(py, (500i32, error_msg.clone())),
+                };
 
-                match status_code {
-                    Some(409) => {
-                        // CosmosResourceExistsError for 409 Conflict
-                        match exists_error.call1(py, (409i32, error_msg.clone())) {
-                            Ok(exc) => PyErr::from_value(exc.as_ref(py)),
-                            Err(_) => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error_msg)
-                        }
-                    }
-                    Some(404) => {
-                        // CosmosResourceNotFoundError for 404 Not Found
-                        match not_found_error.call1(py, (404i32, error_msg.clone())) {
-                            Ok(exc) => PyErr::from_value(exc.as_ref(py)),
-                            Err(_) => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error_msg)
-                        }
-                    }
-                    Some(412) => {
-                        // CosmosAccessConditionFailedError for 412 Precondition Failed
-                        match precondition_error.call1(py, (412i32, error_msg.clone())) {
-                            Ok(exc) => PyErr::from_value(exc.as_ref(py)),
-                            Err(_) => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error_msg)
+                match exc_result {
+                    Ok(exc) => {
+                        if let Some(retry_after_ms) = retry_after_ms {
+                            // Best-effort: callers implementing backoff can read this
+                            // without having to re-parse headers themselves.
+                            let _ = exc.setattr(py, "retry_after_ms", retry_after_ms);
                         }
-                    }
-                    _ => {
-                        // Default to CosmosHttpResponseError for other errors
-                        match http_error.call1(py, (500i32, error_msg.clone())) {
-                            Ok(exc) => PyErr::from_value(exc.as_ref(py)),
-                            Err(_) => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error_msg)
+                        if let Some(sub_status) = sub_status {
+                            // Best-effort: e.g. the sub-status of the batch operation that
+                            // caused a rollback, machine-readable instead of parsed out of the
+                            // message.
+                            let _ = exc.setattr(py, "sub_status", sub_status);
                         }
+                        PyErr::from_value(exc.as_ref(py))
                     }
+                    Err(_) => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(error_msg),
                 }
             }
             Err(_) => {
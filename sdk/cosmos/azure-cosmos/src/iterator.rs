@@ -0,0 +1,70 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::Value;
+use std::collections::HashMap;
+use crate::diagnostics::CosmosResponseDiagnostics;
+
+/// One page of results: the items on this page, the continuation token to resume from
+/// (`None` once the pager is exhausted), and the per-page response headers.
+pub struct Page {
+    pub items: Vec<Value>,
+    pub continuation_token: Option<String>,
+    pub headers: HashMap<String, String>,
+}
+
+type PageFetcher = Box<dyn FnMut() -> PyResult<Option<Page>> + Send>;
+
+/// Python-facing iterator over a Cosmos `Pageable` stream. Each `__next__` call pulls
+/// exactly one page through `TOKIO_RUNTIME.block_on` rather than draining the whole
+/// stream up front, so callers can resume a large listing/query from `max_item_count`
+/// and `continuation_token` instead of materializing everything into memory.
+#[pyclass]
+pub struct QueryIterator {
+    fetch_next: Option<PageFetcher>,
+}
+
+impl QueryIterator {
+    pub fn new(fetch_next: PageFetcher) -> Self {
+        Self { fetch_next: Some(fetch_next) }
+    }
+}
+
+#[pymethods]
+impl QueryIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Returns `(items, continuation_token, diagnostics)` for the next page, or raises
+    /// `StopIteration` once the pager is exhausted.
+    #[allow(clippy::type_complexity)]
+    fn __next__<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+    ) -> PyResult<Option<(Vec<&'py PyDict>, Option<String>, CosmosResponseDiagnostics)>> {
+        // Release the GIL while the fetcher blocks on the shared Tokio runtime: a token
+        // credential's get_token runs on a separate thread via spawn_blocking and needs to
+        // reacquire the GIL itself, which would deadlock against this thread otherwise.
+        let page = match slf.fetch_next.as_mut() {
+            Some(fetch) => py.allow_threads(|| fetch())?,
+            None => None,
+        };
+
+        let page = match page {
+            Some(page) => page,
+            None => {
+                // Exhausted: drop the fetcher so subsequent calls stay terminal.
+                slf.fetch_next = None;
+                return Ok(None);
+            }
+        };
+
+        let items = page.items.iter()
+            .map(|item| crate::utils::json_value_to_py_dict(py, item))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let diagnostics = CosmosResponseDiagnostics::from_headers(page.headers, 0.0);
+
+        Ok(Some((items, page.continuation_token, diagnostics)))
+    }
+}
@@ -1,15 +1,23 @@
 use pyo3::prelude::*;
 
+mod async_client;
 mod client;
 mod database;
 mod container;
+mod diagnostics;
 mod exceptions;
+mod iterator;
+mod retry;
 mod types;
 mod utils;
 
+use async_client::{AsyncCosmosClient, AsyncDatabaseClient, AsyncContainerClient};
 use client::CosmosClient;
 use database::DatabaseClient;
 use container::ContainerClient;
+use diagnostics::CosmosResponseDiagnostics;
+use iterator::QueryIterator;
+use types::PartitionKey;
 
 /// Azure Cosmos DB Python SDK - Rust native extension
 #[pymodule]
@@ -18,7 +26,13 @@ fn _rust(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<CosmosClient>()?;
     m.add_class::<DatabaseClient>()?;
     m.add_class::<ContainerClient>()?;
-    
+    m.add_class::<QueryIterator>()?;
+    m.add_class::<CosmosResponseDiagnostics>()?;
+    m.add_class::<PartitionKey>()?;
+    m.add_class::<AsyncCosmosClient>()?;
+    m.add_class::<AsyncDatabaseClient>()?;
+    m.add_class::<AsyncContainerClient>()?;
+
     // Note: We use the existing Python exception classes from azure.cosmos.exceptions
     // instead of registering our own. See exceptions.rs for the mapping logic.
 
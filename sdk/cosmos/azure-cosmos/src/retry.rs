@@ -0,0 +1,150 @@
+use std::time::Duration;
+use azure_core::http::headers::HeaderName;
+use typespec::error::{Error as TypeSpecError, ErrorKind};
+
+/// Retry knobs mirrored from the other Cosmos SDKs and accepted as `CosmosClient` kwargs:
+/// `retry_total` caps the number of retries, `retry_backoff_max` (milliseconds, stored as
+/// `retry_backoff_max_ms`) bounds the exponential backoff used for transient 5xx/connection
+/// errors, and `retry_fixed_interval_ms` adds a
+/// flat floor on top of both that and the throttling wait.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub retry_total: u32,
+    pub retry_backoff_max_ms: u64,
+    pub retry_fixed_interval_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retry_total: 9,
+            retry_backoff_max_ms: 30_000,
+            retry_fixed_interval_ms: 0,
+        }
+    }
+}
+
+/// How many times a call was retried and the cumulative request charge (RUs) consumed by
+/// the attempts that were throttled or otherwise failed; the successful attempt's own
+/// charge is not included (see `RetryPolicy::execute`).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RetryStats {
+    pub retry_count: u32,
+    pub request_charge: f64,
+}
+
+impl RetryPolicy {
+    /// Runs `attempt` until it succeeds, exhausts `retry_total`, or hits a non-retryable
+    /// error. On HTTP 429 (RU throttling), waits `max(x-ms-retry-after-ms, computed backoff)`.
+    /// On a transient 5xx or connection-level error, applies exponential backoff with jitter.
+    /// Never retries 409 (conflict) or 412 (precondition failed) - those are outcomes of the
+    /// request, not transient failures, and retrying them would be incorrect for non-idempotent
+    /// operations like create.
+    ///
+    /// `attempt`'s `Ok` charge isn't tracked here - `T` may not carry response headers (e.g.
+    /// a stream's unwrapped `Page`) - so callers should add the successful response's own
+    /// `x-ms-request-charge` to `RetryStats::request_charge` themselves; this only accounts
+    /// for the charge consumed by attempts that failed along the way.
+    pub async fn execute<T, F, Fut>(&self, mut attempt: F) -> (Result<T, TypeSpecError>, RetryStats)
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, TypeSpecError>>,
+    {
+        let mut stats = RetryStats::default();
+
+        loop {
+            let result = attempt().await;
+            let err = match &result {
+                Ok(_) => return (result, stats),
+                Err(err) => err,
+            };
+
+            stats.request_charge += err.http_response_headers()
+                .and_then(|h| h.get_optional_str(&HeaderName::from_static("x-ms-request-charge")))
+                .and_then(|s| s.parse::<f64>().ok())
+                .unwrap_or(0.0);
+
+            if stats.retry_count >= self.retry_total {
+                return (result, stats);
+            }
+
+            match self.retry_delay(err, stats.retry_count) {
+                Some(delay) => {
+                    stats.retry_count += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                None => return (result, stats),
+            }
+        }
+    }
+
+    fn retry_delay(&self, err: &TypeSpecError, attempt: u32) -> Option<Duration> {
+        match err.kind() {
+            ErrorKind::HttpResponse { status, .. } => {
+                let status = *status as u16;
+                match status {
+                    // Results of the request itself, not transient failures - retrying a
+                    // non-idempotent create/replace against a stale precondition would be wrong.
+                    409 | 412 => None,
+                    429 => {
+                        let retry_after_ms = err.http_response_headers()
+                            .and_then(|h| h.get_optional_str(&HeaderName::from_static("x-ms-retry-after-ms")))
+                            .and_then(|s| s.parse::<u64>().ok())
+                            .unwrap_or(0);
+                        let wait_ms = retry_after_ms
+                            .max(self.backoff_ms(attempt))
+                            .max(self.retry_fixed_interval_ms);
+                        Some(Duration::from_millis(wait_ms))
+                    }
+                    500..=599 => Some(Duration::from_millis(self.backoff_ms(attempt).max(self.retry_fixed_interval_ms))),
+                    _ => None,
+                }
+            }
+            // Connection/timeout/other transport-level errors carry no status code but are
+            // just as transient as a 503, so they get the same backoff treatment.
+            _ => Some(Duration::from_millis(self.backoff_ms(attempt).max(self.retry_fixed_interval_ms))),
+        }
+    }
+
+    fn backoff_ms(&self, attempt: u32) -> u64 {
+        let base = 100u64.saturating_mul(1u64 << attempt.min(10));
+        (base + jitter_ms(base)).min(self.retry_backoff_max_ms)
+    }
+}
+
+/// Cheap jitter (adds 0 to 25% of `base`) without pulling in a `rand` dependency for a
+/// single use site; good enough to avoid synchronized retry storms across clients.
+fn jitter_ms(base: u64) -> u64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0) as u64;
+    nanos % (base / 4 + 1)
+}
+
+/// Parse the retry kwargs accepted by `CosmosClient::new` into a `RetryPolicy`, falling back
+/// to the defaults for any that are omitted.
+pub fn policy_from_kwargs(kwargs: Option<&pyo3::types::PyDict>) -> pyo3::PyResult<RetryPolicy> {
+    let defaults = RetryPolicy::default();
+    let mut policy = defaults;
+
+    if let Some(kw) = kwargs {
+        if let Ok(Some(value)) = kw.get_item("retry_total") {
+            if !value.is_none() {
+                policy.retry_total = value.extract::<u32>()?;
+            }
+        }
+        if let Ok(Some(value)) = kw.get_item("retry_backoff_max") {
+            if !value.is_none() {
+                policy.retry_backoff_max_ms = value.extract::<u64>()?;
+            }
+        }
+        if let Ok(Some(value)) = kw.get_item("retry_fixed_interval_ms") {
+            if !value.is_none() {
+                policy.retry_fixed_interval_ms = value.extract::<u64>()?;
+            }
+        }
+    }
+
+    Ok(policy)
+}
@@ -1,6 +1,10 @@
 use pyo3::prelude::*;
 use azure_data_cosmos::PartitionKey as RustPartitionKey;
 
+/// A partition key value, usable anywhere a plain scalar/list/tuple partition key is
+/// accepted. Exists so callers can pass around a typed, reusable object instead of a raw
+/// Python value; conversion itself is delegated to `utils::python_to_partition_key` so
+/// there's one place that knows how to shred a value into a Rust `PartitionKey`.
 #[derive(Debug, Clone)]
 #[pyclass]
 pub struct PartitionKey {
@@ -18,17 +22,6 @@ impl PartitionKey {
 
 impl PartitionKey {
     pub fn to_rust_partition_key(&self, py: Python) -> PyResult<RustPartitionKey> {
-        // Convert Python value to Rust PartitionKey
-        if let Ok(s) = self.value.extract::<String>(py) {
-            Ok(RustPartitionKey::from(s))
-        } else if let Ok(i) = self.value.extract::<i64>(py) {
-            Ok(RustPartitionKey::from(i))
-        } else if let Ok(f) = self.value.extract::<f64>(py) {
-            Ok(RustPartitionKey::from(f))
-        } else {
-            Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
-                "Partition key must be string, int, or float"
-            ))
-        }
+        crate::utils::python_to_partition_key(py, self.value.as_ref(py))
     }
 }
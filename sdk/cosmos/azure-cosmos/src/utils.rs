@@ -2,9 +2,12 @@ use pyo3::prelude::*;
 use pyo3::types::PyDict;
 use serde_json::Value;
 use std::collections::HashMap;
-use pythonize::depythonize;
+use pythonize::{depythonize, pythonize};
 use azure_core::http::Response;
 use azure_core::http::headers::{HeaderName, HeaderValue};
+use azure_data_cosmos::PartitionKey as RustPartitionKey;
+use azure_data_cosmos::{BatchOperation, PatchDocument, PatchOperation};
+use pyo3::types::{PyList, PyTuple};
 
 /// Convert Python object (dict or string) to serde_json::Value
 /// Hybrid approach: accepts both PyDict (PyO3 native serialization) and String (direct serde parsing)
@@ -40,11 +43,25 @@ pub fn py_dict_to_json(_py: Python, dict: &PyDict) -> PyResult<Value> {
 
 /// Convert serde_json::Value to Python dict
 pub fn json_to_py_dict(py: Python, value: &Value) -> PyResult<PyObject> {
-    let json_str = serde_json::to_string(value)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("JSON serialization error: {}", e)))?;
-    
-    let json_module = py.import("json")?;
-    json_module.call_method1("loads", (json_str,))?.extract()
+    json_value_to_pyobject(py, value)
+}
+
+/// Convert a serde_json::Value directly into a Python object via pyo3's native conversion
+/// APIs (through `pythonize`), skipping a `serde_json::to_string` + `json.loads` round
+/// trip. Preserves numeric/bool/null fidelity exactly like `pythonize` does
+/// for any other `Serialize` type.
+pub fn json_value_to_pyobject(py: Python, value: &Value) -> PyResult<PyObject> {
+    pythonize(py, value)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Failed to convert JSON value to Python object: {}", e)))
+}
+
+/// Same as `json_value_to_pyobject`, downcast to a `PyDict` for callers that know the
+/// top-level value is a JSON object (e.g. a Cosmos item or resource body).
+pub fn json_value_to_py_dict<'py>(py: Python<'py>, value: &Value) -> PyResult<&'py PyDict> {
+    json_value_to_pyobject(py, value)?
+        .as_ref(py)
+        .downcast::<PyDict>()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Expected a JSON object: {}", e)))
 }
 
 /// Convert Python kwargs to options
@@ -79,9 +96,296 @@ pub fn headers_to_py_dict<'py>(py: Python<'py>, headers: &HashMap<String, String
     Ok(dict)
 }
 
-/// Create an empty headers dict (used when headers are not available)
-pub fn empty_headers_dict<'py>(py: Python<'py>) -> &'py PyDict {
-    PyDict::new(py)
+/// Extract the `max_item_count` paging kwarg shared by list/query methods.
+pub fn extract_max_item_count(kwargs: Option<&PyDict>) -> PyResult<Option<i32>> {
+    if let Some(kw) = kwargs {
+        if let Ok(Some(value)) = kw.get_item("max_item_count") {
+            if !value.is_none() {
+                return Ok(Some(value.extract::<i32>()?));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Extract the `continuation_token` paging kwarg shared by list/query methods.
+pub fn extract_continuation_token(kwargs: Option<&PyDict>) -> PyResult<Option<String>> {
+    if let Some(kw) = kwargs {
+        if let Ok(Some(value)) = kw.get_item("continuation_token") {
+            if !value.is_none() {
+                return Ok(Some(value.extract::<String>()?));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Convert a raw Python value into a Rust partition key. A list/tuple produces a
+/// hierarchical (multi-level) key with up to three components, one per element in order;
+/// a `None` element maps to an explicit "undefined" component rather than erroring. A plain
+/// scalar (including a string, which is otherwise iterable and must not be mistaken for a
+/// sequence of one-character components) falls through to the scalar path below.
+pub fn python_to_partition_key(py: Python, value: &PyAny) -> PyResult<RustPartitionKey> {
+    if let Ok(list) = value.downcast::<PyList>() {
+        let components = list.iter()
+            .map(|element| partition_key_component(py, element))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(RustPartitionKey::from(components));
+    }
+
+    if let Ok(tuple) = value.downcast::<PyTuple>() {
+        let components = tuple.iter()
+            .map(|element| partition_key_component(py, element))
+            .collect::<PyResult<Vec<_>>>()?;
+        return Ok(RustPartitionKey::from(components));
+    }
+
+    scalar_partition_key(value)
+}
+
+fn partition_key_component(_py: Python, value: &PyAny) -> PyResult<RustPartitionKey> {
+    if value.is_none() {
+        return Ok(RustPartitionKey::NONE);
+    }
+    scalar_partition_key(value)
+}
+
+fn scalar_partition_key(value: &PyAny) -> PyResult<RustPartitionKey> {
+    if let Ok(s) = value.extract::<String>() {
+        Ok(RustPartitionKey::from(s))
+    } else if let Ok(i) = value.extract::<i64>() {
+        Ok(RustPartitionKey::from(i))
+    } else if let Ok(f) = value.extract::<f64>() {
+        Ok(RustPartitionKey::from(f))
+    } else {
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "Partition key must be string, int, float, or a list/tuple of those (whose elements may also be None)"
+        ))
+    }
+}
+
+/// Extract a partition key for an item body: prefers an explicit `partition_key` kwarg,
+/// then derives it from `partition_key_paths` (the container's partition key field(s)),
+/// then falls back to a handful of common field names. Shared by the sync and async
+/// container clients so both honor the same lookup order.
+pub fn extract_partition_key_from_body(
+    py: Python,
+    body: &PyDict,
+    partition_key_paths: &[String],
+    kwargs: Option<&PyDict>,
+) -> PyResult<RustPartitionKey> {
+    if let Some(kw) = kwargs {
+        if let Ok(Some(pk)) = kw.get_item("partition_key") {
+            return python_to_partition_key(py, pk);
+        }
+    }
+
+    match partition_key_paths {
+        [] => {}
+        [single_path] => {
+            let field_name = single_path.trim_start_matches('/');
+            if let Ok(Some(value)) = body.get_item(field_name) {
+                return python_to_partition_key(py, value);
+            }
+        }
+        paths => {
+            let components: Vec<Option<&PyAny>> = paths.iter()
+                .map(|path| {
+                    let field_name = path.trim_start_matches('/');
+                    body.get_item(field_name).ok().flatten()
+                })
+                .collect();
+            if components.iter().any(Option::is_some) {
+                let component_keys = components.into_iter()
+                    .map(|value| match value {
+                        Some(value) => scalar_partition_key(value),
+                        None => Ok(RustPartitionKey::NONE),
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                return Ok(RustPartitionKey::from(component_keys));
+            }
+        }
+    }
+
+    let common_pk_fields = ["pk", "partitionKey", "category", "type", "tenantId", "id"];
+    for field in &common_pk_fields {
+        if let Ok(Some(value)) = body.get_item(field) {
+            return python_to_partition_key(py, value);
+        }
+    }
+
+    Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "Partition key not found in body or kwargs"
+    ))
+}
+
+/// Extract the `partition_key` kwarg on its own, for call sites (e.g. a JSON-string body)
+/// that have no dict to derive one from and must be told explicitly.
+pub fn extract_partition_key_from_kwargs(py: Python, kwargs: Option<&PyDict>) -> PyResult<RustPartitionKey> {
+    if let Some(kw) = kwargs {
+        if let Ok(Some(pk)) = kw.get_item("partition_key") {
+            return python_to_partition_key(py, pk);
+        }
+    }
+
+    Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+        "Partition key must be provided in kwargs when body is a JSON string"
+    ))
+}
+
+/// Extract the `etag`/`match_condition` kwarg shared by the write methods that support
+/// optimistic concurrency (upsert/replace/delete/patch).
+pub fn extract_etag(kwargs: Option<&PyDict>) -> PyResult<Option<String>> {
+    if let Some(kw) = kwargs {
+        if let Ok(Some(value)) = kw.get_item("etag") {
+            if !value.is_none() {
+                return Ok(Some(value.extract::<String>()?));
+            }
+        }
+        if let Ok(Some(value)) = kw.get_item("match_condition") {
+            if !value.is_none() {
+                return Ok(Some(value.extract::<String>()?));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Parse a `&PyList` of patch-operation dicts (`{"op": ..., "path": ..., "value"/"from": ...}`)
+/// into a `PatchDocument`. Shared by the sync and async container clients.
+pub fn parse_patch_operations(py: Python, patch_operations: &PyList) -> PyResult<PatchDocument> {
+    let mut doc = PatchDocument::default();
+
+    for entry in patch_operations.iter() {
+        let op: &PyDict = entry.extract()?;
+        let op_name = op.get_item("op")?
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("patch operation missing 'op'"))?
+            .extract::<String>()?;
+        let path = op.get_item("path")?
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("patch operation missing 'path'"))?
+            .extract::<String>()?;
+
+        let operation = match op_name.as_str() {
+            "add" => PatchOperation::Add(path, patch_value(py, op)?),
+            "set" => PatchOperation::Set(path, patch_value(py, op)?),
+            "replace" => PatchOperation::Replace(path, patch_value(py, op)?),
+            "remove" => PatchOperation::Remove(path),
+            "incr" => PatchOperation::Increment(path, patch_value(py, op)?),
+            "move" => {
+                let from = op.get_item("from")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("move operation requires 'from'"))?
+                    .extract::<String>()?;
+                PatchOperation::Move(from, path)
+            }
+            other => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Unknown patch op '{}'; expected one of add/set/replace/remove/incr/move", other)
+            )),
+        };
+
+        doc.push(operation);
+    }
+
+    Ok(doc)
+}
+
+fn patch_value(py: Python, op: &PyDict) -> PyResult<Value> {
+    let value = op.get_item("value")?
+        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("patch operation requires 'value'"))?;
+    py_object_to_json(py, value)
+}
+
+/// Parse a `&PyList` of batch operations - each entry either a dict (see
+/// `parse_batch_operation_dict`) or a tuple like `("create", body)` (see
+/// `parse_batch_operation_tuple`) - into `Vec<BatchOperation>`. Shared by the sync and
+/// async container clients.
+pub fn parse_batch_operations(py: Python, batch_operations: &PyList) -> PyResult<Vec<BatchOperation>> {
+    let mut operations = Vec::with_capacity(batch_operations.len());
+
+    for entry in batch_operations.iter() {
+        let operation = if let Ok(op) = entry.downcast::<PyDict>() {
+            parse_batch_operation_dict(py, op)?
+        } else if let Ok(op) = entry.downcast::<PyTuple>() {
+            parse_batch_operation_tuple(py, op)?
+        } else {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "batch operation must be a dict or a tuple like (\"create\", body)"
+            ));
+        };
+
+        operations.push(operation);
+    }
+
+    Ok(operations)
+}
+
+fn parse_batch_operation_tuple(py: Python, op: &PyTuple) -> PyResult<BatchOperation> {
+    let op_type = op.get_item(0)?.extract::<String>()?;
+
+    match op_type.as_str() {
+        "create" => Ok(BatchOperation::Create(py_object_to_json(py, op.get_item(1)?)?)),
+        "upsert" => Ok(BatchOperation::Upsert(py_object_to_json(py, op.get_item(1)?)?)),
+        "replace" => {
+            let id = op.get_item(1)?.extract::<String>()?;
+            Ok(BatchOperation::Replace(id, py_object_to_json(py, op.get_item(2)?)?))
+        }
+        "delete" => {
+            let id = op.get_item(1)?.extract::<String>()?;
+            Ok(BatchOperation::Delete(id))
+        }
+        "patch" => {
+            let id = op.get_item(1)?.extract::<String>()?;
+            Ok(BatchOperation::Patch(id, py_object_to_json(py, op.get_item(2)?)?))
+        }
+        other => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            format!("Unknown batch operation type '{}'; expected one of create/upsert/replace/delete/patch", other)
+        )),
+    }
+}
+
+fn parse_batch_operation_dict(py: Python, op: &PyDict) -> PyResult<BatchOperation> {
+    let op_type = op.get_item("operation_type")?
+        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("batch operation missing 'operation_type'"))?
+        .extract::<String>()?;
+
+    let operation = match op_type.as_str() {
+        "create" => {
+            let body = op.get_item("resource_body")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("create operation requires 'resource_body'"))?;
+            BatchOperation::Create(py_object_to_json(py, body)?)
+        }
+        "upsert" => {
+            let body = op.get_item("resource_body")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("upsert operation requires 'resource_body'"))?;
+            BatchOperation::Upsert(py_object_to_json(py, body)?)
+        }
+        "replace" => {
+            let id = op.get_item("id")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("replace operation requires 'id'"))?
+                .extract::<String>()?;
+            let body = op.get_item("resource_body")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("replace operation requires 'resource_body'"))?;
+            BatchOperation::Replace(id, py_object_to_json(py, body)?)
+        }
+        "delete" => {
+            let id = op.get_item("id")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("delete operation requires 'id'"))?
+                .extract::<String>()?;
+            BatchOperation::Delete(id)
+        }
+        "patch" => {
+            let id = op.get_item("id")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("patch operation requires 'id'"))?
+                .extract::<String>()?;
+            let patch_ops = op.get_item("patch_operations")?
+                .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("patch operation requires 'patch_operations'"))?;
+            BatchOperation::Patch(id, py_object_to_json(py, patch_ops)?)
+        }
+        other => return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            format!("Unknown batch operation_type '{}'; expected one of create/upsert/replace/delete/patch", other)
+        )),
+    };
+
+    Ok(operation)
 }
 
 /// Extract response headers from Azure SDK Response and convert to Python dict